@@ -0,0 +1,99 @@
+use std::str::FromStr;
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use k8s_version::{ApiVersion, ApiVersionRef, Level, Version};
+use test_support::random_versions;
+
+// The benchmarks only use some of the helpers shared with the unit tests.
+#[allow(dead_code)]
+#[path = "../src/test_support.rs"]
+mod test_support;
+
+const INPUTS: &[&str] = &["v1", "v2beta1", "v1alpha12", "v10beta3"];
+const API_VERSION_INPUTS: &[&str] = &["v1", "apps/v1", "certificates.k8s.io/v1beta1"];
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+
+    group.bench_function("from_str", |b| {
+        b.iter(|| {
+            for input in INPUTS {
+                black_box(Version::from_str(black_box(input)).unwrap());
+            }
+        })
+    });
+
+    group.bench_function("from_ascii_unchecked", |b| {
+        b.iter(|| {
+            for input in INPUTS {
+                black_box(Version::from_ascii_unchecked(black_box(input)).unwrap());
+            }
+        })
+    });
+
+    group.finish();
+}
+
+fn parse_api_version(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_api_version");
+
+    group.bench_function("from_str", |b| {
+        b.iter(|| {
+            for input in API_VERSION_INPUTS {
+                black_box(ApiVersion::from_str(black_box(input)).unwrap());
+            }
+        })
+    });
+
+    group.bench_function("from_str_ref", |b| {
+        b.iter(|| {
+            for input in API_VERSION_INPUTS {
+                black_box(ApiVersionRef::from_str_ref(black_box(input)).unwrap());
+            }
+        })
+    });
+
+    group.finish();
+}
+
+fn format(c: &mut Criterion) {
+    let versions: Vec<_> = INPUTS
+        .iter()
+        .map(|input| Version::from_str(input).unwrap())
+        .collect();
+
+    let mut group = c.benchmark_group("format");
+
+    group.bench_function("to_string", |b| {
+        b.iter(|| {
+            for version in &versions {
+                black_box(black_box(version).to_string());
+            }
+        })
+    });
+
+    group.bench_function("to_owned_string", |b| {
+        b.iter(|| {
+            for version in &versions {
+                black_box(black_box(version).to_owned_string());
+            }
+        })
+    });
+
+    group.finish();
+}
+
+fn sort(c: &mut Criterion) {
+    let versions = random_versions(100_000);
+
+    c.bench_function("sort/priority_cmp", |b| {
+        b.iter_batched(
+            || versions.clone(),
+            |mut versions| versions.sort_by(|lhs, rhs| lhs.priority_cmp(rhs)),
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+criterion_group!(benches, parse, parse_api_version, format, sort);
+criterion_main!(benches);
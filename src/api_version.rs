@@ -1,13 +1,50 @@
-use std::{cmp::Ordering, fmt::Display, str::FromStr};
+use alloc::{
+    collections::BTreeSet,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    cmp::Ordering,
+    fmt::Display,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
 
-use snafu::{ResultExt, Snafu};
+use snafu::{ensure, ResultExt, Snafu};
 
-use crate::{Version, VersionParseError};
+use crate::{
+    validate_group, ApiVersionRef, GroupValidationError, ParseOptions, Version, VersionParseError,
+};
 
 #[derive(Debug, PartialEq, Snafu)]
 pub enum ApiVersionParseError {
+    #[snafu(display("empty API version"))]
+    Empty,
+
+    #[snafu(display("API version is not valid UTF-8"))]
+    InvalidUtf8 { source: core::str::Utf8Error },
+
     #[snafu(display("failed to parse version"))]
     ParseVersion { source: VersionParseError },
+
+    #[snafu(display("group consists of {labels} labels, exceeding the maximum of {max}"))]
+    TooManyGroupLabels { labels: usize, max: usize },
+
+    #[snafu(display("invalid group"))]
+    InvalidGroup { source: GroupValidationError },
+}
+
+impl From<VersionParseError> for ApiVersionParseError {
+    fn from(source: VersionParseError) -> Self {
+        Self::ParseVersion { source }
+    }
+}
+
+impl From<GroupValidationError> for ApiVersionParseError {
+    fn from(source: GroupValidationError) -> Self {
+        Self::InvalidGroup { source }
+    }
 }
 
 /// A Kubernetes API version with the `(<GROUP>/)<VERSION>` format, for example
@@ -16,6 +53,35 @@ pub enum ApiVersionParseError {
 /// The `<VERSION>` string must follow the DNS label format defined [here][1].
 /// The `<GROUP>` string must be lower case and must be a valid DNS subdomain.
 ///
+/// A missing and an empty group both denote the core group. They are treated
+/// as equal by the [`PartialEq`] and [`Hash`] implementations.
+///
+/// Unlike [`Version`], API versions are only [`Clone`], because they own the
+/// group [`String`].
+///
+/// With the `serde` feature enabled, API versions are (de)serialized as
+/// strings, like `apps/v1`. To embed an API version in a struct which maps to
+/// a Kubernetes object, rename the field to the conventional `apiVersion`:
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # fn main() {
+/// use k8s_version::ApiVersion;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Object {
+///     #[serde(rename = "apiVersion")]
+///     api_version: ApiVersion,
+/// }
+///
+/// let object: Object = serde_json::from_str(r#"{"apiVersion": "apps/v1"}"#).unwrap();
+/// assert_eq!(object.api_version.to_string(), "apps/v1");
+/// # }
+/// # #[cfg(not(feature = "serde"))]
+/// # fn main() {}
+/// ```
+///
 /// ### See
 ///
 /// - <https://github.com/kubernetes/community/blob/master/contributors/devel/sig-architecture/api-conventions.md#api-conventions>
@@ -23,7 +89,7 @@ pub enum ApiVersionParseError {
 /// - <https://kubernetes.io/docs/reference/using-api/#api-groups>
 ///
 /// [1]: https://github.com/kubernetes/design-proposals-archive/blob/main/architecture/identifiers.md#definitions
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct ApiVersion {
     pub group: Option<String>,
     pub version: Version,
@@ -33,35 +99,991 @@ impl FromStr for ApiVersion {
     type Err = ApiVersionParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let (group, version) = if let Some((group, version)) = input.split_once('/') {
-            // TODO (Techassi): Validate group
-            (
-                Some(group.to_string()),
-                Version::from_str(version).context(ParseVersionSnafu)?,
-            )
-        } else {
-            (None, Version::from_str(input).context(ParseVersionSnafu)?)
-        };
+        Self::from_str_with_options(input, &ParseOptions::default())
+    }
+}
+
+/// Converts into the string form, like [`Display`].
+impl From<&ApiVersion> for String {
+    fn from(api_version: &ApiVersion) -> Self {
+        api_version.to_string()
+    }
+}
+
+impl TryFrom<String> for ApiVersion {
+    type Error = ApiVersionParseError;
+
+    fn try_from(input: String) -> Result<Self, Self::Error> {
+        Self::from_str(&input)
+    }
+}
+
+/// Parses an API version from raw bytes, like a protobuf field. Invalid UTF-8
+/// returns [`ApiVersionParseError::InvalidUtf8`].
+impl TryFrom<&[u8]> for ApiVersion {
+    type Error = ApiVersionParseError;
+
+    fn try_from(input: &[u8]) -> Result<Self, Self::Error> {
+        let input = core::str::from_utf8(input).context(InvalidUtf8Snafu)?;
+        Self::from_str(input)
+    }
+}
+
+impl ApiVersion {
+    /// Creates an API version of the core group, like `v1`.
+    pub fn core(version: Version) -> Self {
+        Self {
+            group: None,
+            version,
+        }
+    }
+
+    /// Creates an API version of a named group, like `apps/v1`. The group is
+    /// validated like in [`ApiVersion::from_str`]. The core group can't be
+    /// created this way, use [`ApiVersion::core`] instead.
+    pub fn grouped(
+        group: impl Into<String>,
+        version: Version,
+    ) -> Result<Self, ApiVersionParseError> {
+        Self::core(version).with_group(group)
+    }
+
+    /// Replaces the group with `group`, which is validated like in
+    /// [`ApiVersion::from_str`], including the default limit of group labels.
+    /// The API version is left unchanged if the group is invalid. Use
+    /// [`ApiVersion::remove_group`] to move the API version to the core group.
+    pub fn set_group(&mut self, group: impl Into<String>) -> Result<(), ApiVersionParseError> {
+        let group = group.into();
+        check_group(&group, &ParseOptions::default())?;
+
+        self.group = Some(group);
+        Ok(())
+    }
+
+    /// Returns this API version with its group replaced by `group`, see
+    /// [`ApiVersion::set_group`].
+    pub fn with_group(mut self, group: impl Into<String>) -> Result<Self, ApiVersionParseError> {
+        self.set_group(group)?;
+        Ok(self)
+    }
+
+    /// Removes the group, which moves this API version to the core group.
+    pub fn remove_group(&mut self) {
+        self.group = None;
+    }
+
+    /// Returns `true` if this API version belongs to the core group, which is
+    /// the case for a missing or empty group.
+    pub fn is_core(&self) -> bool {
+        self.core_normalized_group().is_none()
+    }
+
+    /// Parses an [`ApiVersion`] like [`ApiVersion::from_str`], but allows
+    /// customizing the parsing behaviour using [`ParseOptions`].
+    pub fn from_str_with_options(
+        input: &str,
+        options: &ParseOptions,
+    ) -> Result<Self, ApiVersionParseError> {
+        let normalized = options.normalize(input);
+        ApiVersionRef::from_normalized(&normalized, options).map(Into::into)
+    }
+
+    /// Parses an [`ApiVersion`] after converting ASCII upper case letters to
+    /// lower case, like `Apps/V1` to `apps/v1`. The returned API version is
+    /// always canonical, while [`ApiVersion::from_str`] keeps rejecting upper
+    /// case.
+    pub fn from_str_normalized(input: &str) -> Result<Self, ApiVersionParseError> {
+        Self::from_str_with_options(input, &ParseOptions::lowercase())
+    }
+
+    /// Formats this API version in the `GroupVersion` wire form used by
+    /// Kubernetes apimachinery: `<GROUP>/<VERSION>` for named groups and just
+    /// `<VERSION>` for the core group. Both a missing and an empty group are
+    /// treated as the core group and are never followed by a slash.
+    ///
+    /// See <https://github.com/kubernetes/apimachinery/blob/master/pkg/runtime/schema/group_version.go>
+    pub fn to_group_version_string(&self) -> String {
+        match self.core_normalized_group() {
+            Some(group) => format!("{}/{}", group, self.version),
+            None => self.version.to_string(),
+        }
+    }
+
+    /// Parses an API version from the `GroupVersion` wire form used by
+    /// Kubernetes apimachinery. Input without a slash, like `v1`, is parsed
+    /// as a version of the core group. This is the inverse of
+    /// [`ApiVersion::to_group_version_string`].
+    pub fn from_group_version_string(input: &str) -> Result<Self, ApiVersionParseError> {
+        Self::from_str(input)
+    }
+
+    /// Returns `true` if both API versions belong to the same group. A missing
+    /// and an empty group both denote the core group and are thus considered
+    /// equal.
+    pub fn same_group(&self, other: &Self) -> bool {
+        self.core_normalized_group() == other.core_normalized_group()
+    }
+
+    /// Returns the group, or the empty string `""` for the core group. This is
+    /// how Kubernetes refers to the core group in most APIs.
+    pub fn group_or_core(&self) -> &str {
+        self.core_normalized_group().unwrap_or_default()
+    }
+
+    /// Returns the group for display purposes, which is `"core"` for the core
+    /// group. Use [`ApiVersion::group_or_core`] for machine-readable output.
+    pub fn group_display(&self) -> &str {
+        self.core_normalized_group().unwrap_or("core")
+    }
+
+    /// Alias of [`ApiVersion::group_display`], which returns `"core"` for the
+    /// core group. It exists for callers who expect a `&str` counterpart to
+    /// [`ApiVersion::group_opt`]; prefer [`ApiVersion::group_display`].
+    pub fn group_str(&self) -> &str {
+        self.group_display()
+    }
+
+    /// Returns the borrowed group, or [`None`] for the core group. Both a
+    /// missing and an empty group are treated as the core group.
+    pub fn group_opt(&self) -> Option<&str> {
+        self.core_normalized_group()
+    }
+
+    /// Alias of [`ApiVersion::group_or_core`], which returns the empty string
+    /// `""` for the core group. The name documents the intent when building
+    /// the `apiGroups` field of RBAC rules, which uses the same form.
+    pub fn rbac_api_group(&self) -> &str {
+        self.group_or_core()
+    }
+
+    /// Returns the REST path prefix the API server serves this API version
+    /// under. This is `/api/<VERSION>` for the core group, like `/api/v1`, and
+    /// `/apis/<GROUP>/<VERSION>` for all other groups, like `/apis/apps/v1`.
+    pub fn api_path(&self) -> String {
+        match self.core_normalized_group() {
+            Some(group) => format!("/apis/{}/{}", group, self.version),
+            None => format!("/api/{}", self.version),
+        }
+    }
 
-        Ok(Self { group, version })
+    /// Returns the group, mapping an empty group to [`None`] (the core group).
+    pub(crate) fn core_normalized_group(&self) -> Option<&str> {
+        self.group.as_deref().filter(|group| !group.is_empty())
+    }
+}
+
+/// Checks that `group` consists of at most [`ParseOptions::max_group_labels`]
+/// labels and is a valid API group. This is shared by the parser and the
+/// group setters, so that every constructed API version can be parsed back.
+pub(crate) fn check_group(group: &str, options: &ParseOptions) -> Result<(), ApiVersionParseError> {
+    let labels = group.split('.').count();
+    ensure!(
+        labels <= options.max_group_labels,
+        TooManyGroupLabelsSnafu {
+            labels,
+            max: options.max_group_labels
+        }
+    );
+
+    validate_group(group).context(InvalidGroupSnafu)
+}
+
+/// Returns the versions of all API versions in `items` which belong to
+/// `group`, sorted by descending Kubernetes priority (see
+/// [`Version::priority_cmp`]). A missing or empty `group` selects versions of
+/// the core group.
+pub fn versions_in_group<'a>(items: &'a [ApiVersion], group: Option<&str>) -> Vec<&'a Version> {
+    let group = group.filter(|group| !group.is_empty());
+
+    let mut versions: Vec<_> = items
+        .iter()
+        .filter(|item| item.core_normalized_group() == group)
+        .map(|item| &item.version)
+        .collect();
+
+    versions.sort_by(|lhs, rhs| rhs.priority_cmp(lhs));
+    versions
+}
+
+/// Removes duplicate API versions while preserving the order in which they
+/// were first seen.
+///
+/// Duplicates are detected with a [`BTreeSet`], using the [`Ord`]
+/// implementation instead of [`Hash`], so that this also works without `std`.
+/// [`Ord`] is consistent with [`PartialEq`]: both compare the core-normalized
+/// group, so API versions of the core group are duplicates regardless of
+/// whether the group is missing or empty.
+pub fn dedup_api_versions(items: Vec<ApiVersion>) -> Vec<ApiVersion> {
+    let mut seen = BTreeSet::new();
+    let first_seen: Vec<_> = items.iter().map(|item| seen.insert(item)).collect();
+
+    items
+        .into_iter()
+        .zip(first_seen)
+        .filter_map(|(item, first_seen)| first_seen.then_some(item))
+        .collect()
+}
+
+impl PartialEq for ApiVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.same_group(other) && self.version == other.version
+    }
+}
+
+impl Eq for ApiVersion {}
+
+impl Hash for ApiVersion {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.core_normalized_group().hash(state);
+        self.version.hash(state);
     }
 }
 
 impl PartialOrd for ApiVersion {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        match self.group.partial_cmp(&other.group) {
-            Some(Ordering::Equal) => {}
-            _ => return None,
-        }
-        self.version.partial_cmp(&other.version)
+        Some(self.cmp(other))
+    }
+}
+
+/// API versions are ordered by their group first and by their version second.
+/// Groups are compared lexicographically, with the core group sorting before
+/// every named group. Versions of the same group are compared by their
+/// Kubernetes priority, see [`Version::priority_cmp`].
+///
+/// This means `certificates.k8s.io/v1beta1` is less than
+/// `certificates.k8s.io/v1`, because GA versions have a higher priority than
+/// beta versions. It is also less than `extensions/v1beta1`, because the
+/// group `certificates.k8s.io` sorts before `extensions`, regardless of the
+/// versions.
+impl Ord for ApiVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.core_normalized_group()
+            .cmp(&other.core_normalized_group())
+            .then_with(|| self.version.cmp(&other.version))
+    }
+}
+
+/// Defaults to `v1` of the core group, see [`Version::default`].
+impl Default for ApiVersion {
+    fn default() -> Self {
+        Self::core(Version::default())
     }
 }
 
+/// Formats the API version like [`ApiVersion::to_group_version_string`], so an
+/// empty group is formatted like the core group, without a slash.
 impl Display for ApiVersion {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &self.group {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.core_normalized_group() {
             Some(group) => write!(f, "{}/{}", group, self.version),
             None => write!(f, "{}", self.version),
         }
     }
 }
+
+/// Generates API versions with either the core group or a valid group of up
+/// to three labels, each consisting of up to eight characters.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ApiVersion {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        const ALPHANUMERIC: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+        const LABEL: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789-";
+
+        let group = if u.arbitrary()? {
+            let mut labels = Vec::new();
+
+            for _ in 0..u.int_in_range(1..=3)? {
+                let mut label = vec![*u.choose(ALPHANUMERIC)?];
+
+                for _ in 0..u.int_in_range(0..=6)? {
+                    label.push(*u.choose(LABEL)?);
+                }
+
+                if label.len() > 1 {
+                    label.push(*u.choose(ALPHANUMERIC)?);
+                }
+
+                labels.push(String::from_utf8(label).expect("labels are ASCII"));
+            }
+
+            Some(labels.join("."))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            group,
+            version: u.arbitrary()?,
+        })
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl ApiVersion {
+    /// Returns a [`proptest`] strategy generating valid API versions, with
+    /// either the core group or a short valid DNS subdomain group.
+    pub fn arb() -> impl proptest::strategy::Strategy<Value = ApiVersion> {
+        use proptest::prelude::*;
+
+        let group = r"[a-z0-9]([a-z0-9-]{0,6}[a-z0-9])?(\.[a-z0-9]([a-z0-9-]{0,6}[a-z0-9])?){0,2}";
+
+        (proptest::option::of(group), Version::arb())
+            .prop_map(|(group, version)| ApiVersion { group, version })
+    }
+}
+
+/// Converts into the kube [`GroupVersion`][kube_core::GroupVersion]. The core
+/// group is mapped to the empty group, which is the kube convention.
+#[cfg(feature = "kube")]
+impl From<ApiVersion> for kube_core::GroupVersion {
+    fn from(api_version: ApiVersion) -> Self {
+        Self::gv(
+            api_version.group_or_core(),
+            &api_version.version.to_string(),
+        )
+    }
+}
+
+/// Converts from the kube [`GroupVersion`][kube_core::GroupVersion]. The
+/// empty group is mapped to the core group ([`None`]).
+#[cfg(feature = "kube")]
+impl TryFrom<kube_core::GroupVersion> for ApiVersion {
+    type Error = ApiVersionParseError;
+
+    fn try_from(group_version: kube_core::GroupVersion) -> Result<Self, Self::Error> {
+        Self::from_group_version_string(&group_version.api_version())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ApiVersion {
+    fn schema_name() -> String {
+        "ApiVersion".to_string()
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        use schemars::schema::{InstanceType, Metadata, SchemaObject, StringValidation};
+
+        use crate::version::VERSION_PATTERN;
+
+        // The group is an optional DNS subdomain followed by a slash.
+        const GROUP_PATTERN: &str =
+            r"[a-z0-9]([a-z0-9-]*[a-z0-9])?(\.[a-z0-9]([a-z0-9-]*[a-z0-9])?)*";
+
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            metadata: Some(Box::new(Metadata {
+                description: Some(
+                    "A Kubernetes API version with an optional group/ prefix, like apps/v1 or v1 for the core group"
+                        .to_string(),
+                ),
+                ..Default::default()
+            })),
+            string: Some(Box::new(StringValidation {
+                pattern: Some(format!("^({GROUP_PATTERN}/)?{VERSION_PATTERN}$")),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ApiVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ApiVersion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::{Error, Visitor};
+
+        struct ApiVersionVisitor;
+
+        impl<'de> Visitor<'de> for ApiVersionVisitor {
+            type Value = ApiVersion;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                f.write_str("a Kubernetes API version string")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                ApiVersion::from_str(value).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(ApiVersionVisitor)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("certificates.k8s.io/v1beta1")]
+    #[case("extensions/v1beta1")]
+    #[case("a.b.c.d.e.f.g.h.i.j/v1")]
+    #[case("v1")]
+    fn valid_api_version(#[case] input: &str) {
+        let api_version = ApiVersion::from_str(input).unwrap();
+        assert_eq!(api_version.to_string(), input);
+    }
+
+    #[test]
+    fn invalid_group() {
+        let err = ApiVersion::from_str("apps.BAD.io/v1").unwrap_err();
+        assert_eq!(
+            err,
+            ApiVersionParseError::InvalidGroup {
+                source: GroupValidationError::InvalidCharacter {
+                    label: "BAD".to_string(),
+                    character: 'B',
+                    index: 5,
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn constructors() {
+        let core = ApiVersion::core(Version::ga(1));
+        assert!(core.is_core());
+        assert_eq!(core, ApiVersion::from_str("v1").unwrap());
+
+        let apps = ApiVersion::grouped("apps", Version::ga(1)).unwrap();
+        assert!(!apps.is_core());
+        assert_eq!(apps, ApiVersion::from_str("apps/v1").unwrap());
+
+        let empty = ApiVersion {
+            group: Some(String::new()),
+            version: Version::ga(1),
+        };
+        assert!(empty.is_core());
+    }
+
+    #[rstest]
+    #[case("Apps", GroupValidationError::InvalidCharacter { label: "Apps".into(), character: 'A', index: 0 })]
+    #[case("", GroupValidationError::EmptyLabel { index: 0 })]
+    fn grouped_invalid(#[case] group: &str, #[case] source: GroupValidationError) {
+        let err = ApiVersion::grouped(group, Version::ga(1)).unwrap_err();
+        assert_eq!(err, ApiVersionParseError::InvalidGroup { source });
+    }
+
+    #[rstest]
+    #[case("v1", "apps", "apps/v1")]
+    #[case("batch/v1beta1", "apps", "apps/v1beta1")]
+    #[case("apps/v1", "certificates.k8s.io", "certificates.k8s.io/v1")]
+    fn set_group(#[case] input: &str, #[case] group: &str, #[case] expected: &str) {
+        let mut api_version = ApiVersion::from_str(input).unwrap();
+        api_version.set_group(group).unwrap();
+        assert_eq!(api_version.to_string(), expected);
+
+        let api_version = ApiVersion::from_str(input).unwrap().with_group(group);
+        assert_eq!(api_version.unwrap().to_string(), expected);
+    }
+
+    #[test]
+    fn set_group_invalid() {
+        let mut api_version = ApiVersion::from_str("apps/v1").unwrap();
+        let err = api_version.set_group("Apps").unwrap_err();
+
+        assert!(matches!(err, ApiVersionParseError::InvalidGroup { .. }));
+        assert_eq!(api_version.to_string(), "apps/v1");
+
+        let err = ApiVersion::core(Version::ga(1)).with_group("").unwrap_err();
+        assert!(matches!(err, ApiVersionParseError::InvalidGroup { .. }));
+    }
+
+    #[test]
+    fn set_group_too_many_labels() {
+        let group = "a.b.c.d.e.f.g.h.i.j.k";
+        let expected = ApiVersionParseError::TooManyGroupLabels {
+            labels: 11,
+            max: 10,
+        };
+
+        let mut api_version = ApiVersion::core(Version::ga(1));
+        assert_eq!(api_version.set_group(group), Err(expected));
+        assert!(api_version.is_core());
+
+        let err = ApiVersion::grouped(group, Version::ga(1)).unwrap_err();
+        assert_eq!(
+            err,
+            ApiVersion::from_str(&format!("{group}/v1")).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn remove_group() {
+        let mut api_version = ApiVersion::from_str("apps/v1").unwrap();
+        api_version.remove_group();
+
+        assert!(api_version.is_core());
+        assert_eq!(api_version.to_string(), "v1");
+    }
+
+    #[test]
+    fn default() {
+        let api_version = ApiVersion::default();
+
+        assert!(api_version.is_core());
+        assert_eq!(api_version.to_string(), "v1");
+    }
+
+    #[rstest]
+    #[case("apps/v1")]
+    #[case("v1beta1")]
+    fn into_string(#[case] input: &str) {
+        let api_version = ApiVersion::from_str(input).unwrap();
+        assert_eq!(String::from(&api_version), input);
+    }
+
+    #[test]
+    fn try_from() {
+        let expected = ApiVersion::from_str("apps/v1").unwrap();
+
+        assert_eq!(
+            ApiVersion::try_from(String::from("apps/v1")),
+            Ok(expected.clone())
+        );
+        assert_eq!(ApiVersion::try_from(b"apps/v1".as_slice()), Ok(expected));
+        assert!(matches!(
+            ApiVersion::try_from(b"apps/\xffv1".as_slice()),
+            Err(ApiVersionParseError::InvalidUtf8 { .. })
+        ));
+    }
+
+    #[test]
+    fn uppercase_group() {
+        let err = ApiVersion::from_str("Foo/v1").unwrap_err();
+        assert_eq!(
+            err,
+            ApiVersionParseError::InvalidGroup {
+                source: GroupValidationError::InvalidCharacter {
+                    label: "Foo".to_string(),
+                    character: 'F',
+                    index: 0,
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn group_too_long() {
+        let group = [
+            "a".repeat(63),
+            "b".repeat(63),
+            "c".repeat(63),
+            "d".repeat(63),
+        ]
+        .join(".");
+        let err = ApiVersion::from_str(&format!("{group}/v1")).unwrap_err();
+        assert_eq!(
+            err,
+            ApiVersionParseError::InvalidGroup {
+                source: GroupValidationError::TooLong { length: 255 }
+            }
+        );
+    }
+
+    #[rstest]
+    #[case("a.b.c.d.e.f.g.h.i.j.k/v1", 11, 10)]
+    fn too_many_group_labels(#[case] input: &str, #[case] labels: usize, #[case] max: usize) {
+        let err = ApiVersion::from_str(input).unwrap_err();
+        assert_eq!(
+            err,
+            ApiVersionParseError::TooManyGroupLabels { labels, max }
+        )
+    }
+
+    #[rstest]
+    #[case(ApiVersion { group: None, version: Version::new(1, None) }, "v1")]
+    #[case(ApiVersion { group: Some(String::new()), version: Version::new(1, None) }, "v1")]
+    #[case(ApiVersion { group: Some("apps".into()), version: Version::new(1, None) }, "apps/v1")]
+    fn to_group_version_string(#[case] input: ApiVersion, #[case] expected: &str) {
+        assert_eq!(input.to_group_version_string(), expected);
+    }
+
+    #[rstest]
+    #[case(ApiVersion { group: None, version: Version::new(1, None) }, "/api/v1")]
+    #[case(ApiVersion { group: Some(String::new()), version: Version::new(1, None) }, "/api/v1")]
+    #[case(ApiVersion { group: Some("apps".into()), version: Version::new(1, None) }, "/apis/apps/v1")]
+    #[case(ApiVersion::from_str("certificates.k8s.io/v1beta1").unwrap(), "/apis/certificates.k8s.io/v1beta1")]
+    fn api_path(#[case] input: ApiVersion, #[case] expected: &str) {
+        assert_eq!(input.api_path(), expected);
+    }
+
+    #[rstest]
+    #[case("v1", None)]
+    #[case("apps/v1", Some("apps"))]
+    #[case("certificates.k8s.io/v1beta1", Some("certificates.k8s.io"))]
+    fn from_group_version_string(#[case] input: &str, #[case] group: Option<&str>) {
+        let api_version = ApiVersion::from_group_version_string(input).unwrap();
+        assert_eq!(api_version.group.as_deref(), group);
+        assert_eq!(api_version.to_group_version_string(), input);
+    }
+
+    #[rstest]
+    #[case("v1", "v1", true)]
+    #[case("v1", "v2beta1", true)]
+    #[case("apps/v1", "apps/v2", true)]
+    #[case("apps/v1", "batch/v1", false)]
+    #[case("v1", "apps/v1", false)]
+    fn same_group(#[case] input: &str, #[case] other: &str, #[case] expected: bool) {
+        let input = ApiVersion::from_str(input).unwrap();
+        let other = ApiVersion::from_str(other).unwrap();
+        assert_eq!(input.same_group(&other), expected);
+    }
+
+    #[rstest]
+    #[case(
+        "certificates.k8s.io/v1beta1",
+        "certificates.k8s.io/v1",
+        Ordering::Less
+    )]
+    #[case("apps/v2", "apps/v1", Ordering::Greater)]
+    #[case("apps/v1", "apps/v1", Ordering::Equal)]
+    #[case("certificates.k8s.io/v1beta1", "extensions/v1beta1", Ordering::Less)]
+    #[case("certificates.k8s.io/v1", "extensions/v1alpha1", Ordering::Less)]
+    #[case("v2alpha1", "apps/v1", Ordering::Less)]
+    #[case("batch/v1", "v1", Ordering::Greater)]
+    fn ord(#[case] lhs: &str, #[case] rhs: &str, #[case] expected: Ordering) {
+        let lhs = ApiVersion::from_str(lhs).unwrap();
+        let rhs = ApiVersion::from_str(rhs).unwrap();
+        assert_eq!(lhs.cmp(&rhs), expected);
+        assert_eq!(lhs.partial_cmp(&rhs), Some(expected));
+    }
+
+    #[test]
+    fn ord_empty_group_is_core() {
+        let empty = ApiVersion {
+            group: Some(String::new()),
+            version: Version::new(1, None),
+        };
+        let core = ApiVersion::from_str("v1").unwrap();
+        assert_eq!(empty.cmp(&core), Ordering::Equal);
+        assert!(empty < ApiVersion::from_str("apps/v1").unwrap());
+    }
+
+    #[test]
+    fn same_group_empty_is_core() {
+        let empty = ApiVersion {
+            group: Some(String::new()),
+            version: Version::new(1, None),
+        };
+
+        assert!(empty.same_group(&ApiVersion::from_str("v1").unwrap()));
+    }
+
+    #[test]
+    fn hash_empty_group_is_core() {
+        use std::collections::HashMap;
+
+        let empty = ApiVersion {
+            group: Some(String::new()),
+            version: Version::new(1, None),
+        };
+
+        let mut schemas = HashMap::new();
+        schemas.insert(ApiVersion::from_str("v1").unwrap(), "core");
+        schemas.insert(ApiVersion::from_str("apps/v1").unwrap(), "apps");
+
+        assert_eq!(schemas.get(&empty), Some(&"core"));
+        assert_eq!(schemas.insert(empty, "empty"), Some("core"));
+        assert_eq!(schemas.len(), 2);
+    }
+
+    #[rstest]
+    #[case(Some("apps"), &["v1", "v2beta1", "v1beta2", "v1alpha1"])]
+    #[case(None, &["v1", "v1beta1"])]
+    #[case(Some(""), &["v1", "v1beta1"])]
+    #[case(Some("batch"), &["v1"])]
+    #[case(Some("unknown"), &[])]
+    fn versions_in_group(#[case] group: Option<&str>, #[case] expected: &[&str]) {
+        let items: Vec<_> = [
+            "apps/v1beta2",
+            "v1beta1",
+            "apps/v1alpha1",
+            "batch/v1",
+            "apps/v1",
+            "v1",
+            "apps/v2beta1",
+        ]
+        .iter()
+        .map(|input| ApiVersion::from_str(input).unwrap())
+        .collect();
+
+        let versions: Vec<_> = super::versions_in_group(&items, group)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(versions, expected);
+    }
+
+    #[rstest]
+    #[case("v1", "", "core")]
+    #[case("apps/v1", "apps", "apps")]
+    #[case("certificates.k8s.io/v1", "certificates.k8s.io", "certificates.k8s.io")]
+    fn group_or_core(#[case] input: &str, #[case] group: &str, #[case] display: &str) {
+        let api_version = ApiVersion::from_str(input).unwrap();
+        assert_eq!(api_version.group_or_core(), group);
+        assert_eq!(api_version.group_display(), display);
+    }
+
+    #[rstest]
+    #[case("v1", "core", None)]
+    #[case("apps/v1", "apps", Some("apps"))]
+    #[case(
+        "certificates.k8s.io/v1",
+        "certificates.k8s.io",
+        Some("certificates.k8s.io")
+    )]
+    fn group_str_and_opt(#[case] input: &str, #[case] group: &str, #[case] opt: Option<&str>) {
+        let api_version = ApiVersion::from_str(input).unwrap();
+        assert_eq!(api_version.group_str(), group);
+        assert_eq!(api_version.group_opt(), opt);
+    }
+
+    #[test]
+    fn display_empty_group() {
+        let api_version = ApiVersion {
+            group: Some(String::new()),
+            version: Version::ga(1),
+        };
+
+        assert_eq!(api_version.to_string(), "v1");
+        assert_eq!(
+            ApiVersion::from_str(&api_version.to_string()),
+            Ok(api_version)
+        );
+    }
+
+    #[test]
+    fn group_opt_empty_group() {
+        let api_version = ApiVersion {
+            group: Some(String::new()),
+            version: Version::ga(1),
+        };
+
+        assert_eq!(api_version.group_opt(), None);
+        assert_eq!(api_version.group_str(), "core");
+    }
+
+    #[rstest]
+    #[case("v1", "")]
+    #[case("apps/v1", "apps")]
+    #[case("rbac.authorization.k8s.io/v1", "rbac.authorization.k8s.io")]
+    fn rbac_api_group(#[case] input: &str, #[case] expected: &str) {
+        let api_version = ApiVersion::from_str(input).unwrap();
+        assert_eq!(api_version.rbac_api_group(), expected);
+    }
+
+    #[test]
+    fn core_group() {
+        let lenient =
+            ApiVersion::from_str_with_options("core/v1", &ParseOptions::lenient()).unwrap();
+        assert_eq!(lenient.group, None);
+        assert_eq!(lenient.version, Version::new(1, None));
+
+        let strict = ApiVersion::from_str("core/v1").unwrap();
+        assert_eq!(strict.group.as_deref(), Some("core"));
+    }
+
+    #[test]
+    fn clone_api_version() {
+        let api_version = ApiVersion::from_str("apps/v1").unwrap();
+        let mut cloned = api_version.clone();
+        cloned.group = Some("batch".to_string());
+
+        assert_eq!(api_version.group.as_deref(), Some("apps"));
+        assert_eq!(cloned.version, api_version.version);
+    }
+
+    #[test]
+    fn dedup_api_versions() {
+        let empty_core = ApiVersion {
+            group: Some(String::new()),
+            version: Version::new(1, None),
+        };
+
+        let items = vec![
+            ApiVersion::from_str("apps/v1").unwrap(),
+            ApiVersion::from_str("v1").unwrap(),
+            ApiVersion::from_str("batch/v1").unwrap(),
+            empty_core,
+            ApiVersion::from_str("apps/v1").unwrap(),
+            ApiVersion::from_str("apps/v1beta1").unwrap(),
+        ];
+
+        let deduped: Vec<_> = super::dedup_api_versions(items)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(deduped, ["apps/v1", "v1", "batch/v1", "apps/v1beta1"]);
+    }
+
+    #[rstest]
+    #[case("\"v1\"", None)]
+    #[case("'apps/v1'", Some("apps"))]
+    fn strip_quotes(#[case] input: &str, #[case] group: Option<&str>) {
+        let api_version =
+            ApiVersion::from_str_with_options(input, &ParseOptions::lenient()).unwrap();
+        assert_eq!(api_version.group.as_deref(), group);
+        assert_eq!(api_version.version, Version::new(1, None));
+
+        assert!(ApiVersion::from_str(input).is_err());
+    }
+
+    #[rstest]
+    #[case("V1", "v1")]
+    #[case("Apps/V1BETA2", "apps/v1beta2")]
+    #[case("Certificates.K8s.IO/v1", "certificates.k8s.io/v1")]
+    fn from_str_normalized(#[case] input: &str, #[case] expected: &str) {
+        let api_version = ApiVersion::from_str_normalized(input).unwrap();
+        assert_eq!(api_version.to_string(), expected);
+
+        assert!(ApiVersion::from_str(input).is_err());
+    }
+
+    #[rstest]
+    #[case("\"v1'")]
+    #[case("\"\"v1\"\"")]
+    fn strip_quotes_invalid(#[case] input: &str) {
+        assert!(ApiVersion::from_str_with_options(input, &ParseOptions::lenient()).is_err());
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_api_version() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let bytes: Vec<u8> = (0..4096).map(|i| (i * 31 % 251) as u8).collect();
+        let mut u = Unstructured::new(&bytes);
+
+        while !u.is_empty() {
+            let api_version = ApiVersion::arbitrary(&mut u).unwrap();
+            assert_eq!(
+                ApiVersion::from_str(&api_version.to_string()),
+                Ok(api_version)
+            );
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn proptest_display_round_trip(api_version in ApiVersion::arb()) {
+            proptest::prop_assert_eq!(
+                ApiVersion::from_str(&api_version.to_string()),
+                Ok(api_version)
+            );
+        }
+    }
+
+    #[cfg(feature = "kube")]
+    #[rstest]
+    #[case("certificates.k8s.io/v1beta1", "certificates.k8s.io")]
+    #[case("apps/v1", "apps")]
+    #[case("v1", "")]
+    fn kube_group_version(#[case] input: &str, #[case] group: &str) {
+        let api_version = ApiVersion::from_str(input).unwrap();
+
+        let group_version = kube_core::GroupVersion::from(api_version.clone());
+        assert_eq!(group_version.group, group);
+        assert_eq!(group_version.api_version(), input);
+
+        assert_eq!(ApiVersion::try_from(group_version), Ok(api_version));
+    }
+
+    #[cfg(feature = "kube")]
+    #[test]
+    fn kube_group_version_invalid() {
+        let group_version = kube_core::GroupVersion::gv("apps", "v1gamma1");
+        assert!(ApiVersion::try_from(group_version).is_err());
+    }
+
+    #[cfg(feature = "schemars")]
+    #[rstest]
+    #[case("v1", true)]
+    #[case("apps/v1", true)]
+    #[case("certificates.k8s.io/v1beta1", true)]
+    #[case("Foo/v1", false)]
+    #[case("apps/", false)]
+    #[case("apps/v1gamma1", false)]
+    fn json_schema_pattern(#[case] input: &str, #[case] valid: bool) {
+        let schema = schemars::schema_for!(ApiVersion).schema;
+        let pattern = schema.string.unwrap().pattern.unwrap();
+
+        assert_eq!(regex::Regex::new(&pattern).unwrap().is_match(input), valid);
+        assert_eq!(ApiVersion::from_str(input).is_ok(), valid);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_rename_api_version() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Object {
+            #[serde(rename = "apiVersion")]
+            api_version: ApiVersion,
+        }
+
+        let object = Object {
+            api_version: ApiVersion::from_str("apps/v1").unwrap(),
+        };
+
+        let json = serde_json::to_string(&object).unwrap();
+        assert_eq!(json, r#"{"apiVersion":"apps/v1"}"#);
+        assert_eq!(serde_json::from_str::<Object>(&json).unwrap(), object);
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    #[case("certificates.k8s.io/v1beta1", Some("certificates.k8s.io"))]
+    #[case("apps/v1", Some("apps"))]
+    #[case("v1", None)]
+    fn serde_round_trip(#[case] input: &str, #[case] group: Option<&str>) {
+        let json = serde_json::to_string(input).unwrap();
+        let api_version: ApiVersion = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(api_version.group.as_deref(), group);
+        assert_eq!(serde_json::to_string(&api_version).unwrap(), json);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("   ")]
+    fn empty_api_version(#[case] input: &str) {
+        let err = ApiVersion::from_str(input).unwrap_err();
+        assert_eq!(err, ApiVersionParseError::Empty)
+    }
+
+    #[test]
+    fn custom_max_group_labels() {
+        let options = ParseOptions {
+            max_group_labels: 2,
+            ..Default::default()
+        };
+
+        assert!(ApiVersion::from_str_with_options("apps/v1", &options).is_ok());
+        assert!(ApiVersion::from_str_with_options("k8s.io/v1", &options).is_ok());
+        assert_eq!(
+            ApiVersion::from_str_with_options("certificates.k8s.io/v1", &options).unwrap_err(),
+            ApiVersionParseError::TooManyGroupLabels { labels: 3, max: 2 }
+        );
+    }
+}
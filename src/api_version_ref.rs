@@ -0,0 +1,167 @@
+use core::{fmt::Display, str::FromStr};
+
+use crate::{api_version::check_group, ApiVersion, ApiVersionParseError, ParseOptions, Version};
+
+/// A borrowed [`ApiVersion`], whose group is a slice of the parsed input.
+///
+/// Parsing an [`ApiVersion`] allocates a [`String`][alloc::string::String]
+/// for every named group. [`ApiVersionRef::from_str_ref`] performs the same
+/// validation without allocating, which helps when many API versions are
+/// parsed just to compare them. Use [`ApiVersionRef::to_api_version`] to
+/// convert it into an owned [`ApiVersion`].
+///
+/// API versions are ordered like [`ApiVersion`]s, by their group first and by
+/// their version second.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ApiVersionRef<'a> {
+    group: Option<&'a str>,
+    version: Version,
+}
+
+impl<'a> ApiVersionRef<'a> {
+    /// Parses a borrowed API version like [`ApiVersion::from_str`], without
+    /// allocating.
+    pub fn from_str_ref(input: &'a str) -> Result<Self, ApiVersionParseError> {
+        Self::from_normalized(input, &ParseOptions::default())
+    }
+
+    /// Parses `input`, which must already be normalized using
+    /// [`ParseOptions::normalize`]. Only the options concerning the group are
+    /// applied.
+    pub(crate) fn from_normalized(
+        input: &'a str,
+        options: &ParseOptions,
+    ) -> Result<Self, ApiVersionParseError> {
+        if input.trim().is_empty() {
+            return Err(ApiVersionParseError::Empty);
+        }
+
+        let (group, version) = match input.split_once('/') {
+            Some(("core", version)) if options.normalize_core_group => (None, version),
+            Some((group, version)) => (Some(group), version),
+            None => (None, input),
+        };
+
+        if let Some(group) = group {
+            check_group(group, options)?;
+        }
+
+        let version = Version::from_str(version)?;
+
+        Ok(Self { group, version })
+    }
+
+    /// Returns the group, or [`None`] for the core group.
+    pub fn group(&self) -> Option<&'a str> {
+        self.group
+    }
+
+    /// Returns the version.
+    pub fn version(&self) -> Version {
+        self.version
+    }
+
+    /// Converts this borrowed API version into an owned [`ApiVersion`].
+    pub fn to_api_version(&self) -> ApiVersion {
+        ApiVersion {
+            group: self.group.map(Into::into),
+            version: self.version,
+        }
+    }
+}
+
+impl<'a> From<&'a ApiVersion> for ApiVersionRef<'a> {
+    fn from(api_version: &'a ApiVersion) -> Self {
+        Self {
+            group: api_version.group_opt(),
+            version: api_version.version,
+        }
+    }
+}
+
+impl From<ApiVersionRef<'_>> for ApiVersion {
+    fn from(api_version: ApiVersionRef<'_>) -> Self {
+        api_version.to_api_version()
+    }
+}
+
+impl Display for ApiVersionRef<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.group {
+            Some(group) => write!(f, "{}/{}", group, self.version),
+            None => write!(f, "{}", self.version),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use crate::{GroupValidationError, VersionParseError};
+
+    use super::*;
+
+    #[rstest]
+    #[case("v1", None)]
+    #[case("apps/v1", Some("apps"))]
+    #[case("certificates.k8s.io/v1beta1", Some("certificates.k8s.io"))]
+    fn from_str_ref(#[case] input: &str, #[case] group: Option<&str>) {
+        let api_version = ApiVersionRef::from_str_ref(input).unwrap();
+
+        assert_eq!(api_version.group(), group);
+        assert_eq!(api_version.to_string(), input);
+        assert_eq!(
+            api_version.to_api_version(),
+            ApiVersion::from_str(input).unwrap()
+        );
+    }
+
+    #[test]
+    fn from_str_ref_does_not_allocate() {
+        let input = "certificates.k8s.io/v1beta1";
+        let api_version = ApiVersionRef::from_str_ref(input).unwrap();
+
+        // The group must point into the input instead of a new allocation.
+        let group = api_version.group().unwrap();
+        assert!(core::ptr::eq(group.as_ptr(), input.as_ptr()));
+        assert_eq!(group.len(), "certificates.k8s.io".len());
+    }
+
+    #[rstest]
+    #[case("", ApiVersionParseError::Empty)]
+    #[case("Apps/v1", ApiVersionParseError::InvalidGroup {
+        source: GroupValidationError::InvalidCharacter { label: "Apps".to_string(), character: 'A', index: 0 },
+    })]
+    #[case("apps/v1gamma1", ApiVersionParseError::ParseVersion {
+        source: VersionParseError::InvalidFormat,
+    })]
+    fn from_str_ref_invalid(#[case] input: &str, #[case] expected: ApiVersionParseError) {
+        assert_eq!(ApiVersionRef::from_str_ref(input).unwrap_err(), expected);
+        assert_eq!(ApiVersion::from_str(input).unwrap_err(), expected);
+    }
+
+    #[test]
+    fn from_api_version() {
+        let owned = ApiVersion::from_str("apps/v1").unwrap();
+        let borrowed = ApiVersionRef::from(&owned);
+
+        assert_eq!(borrowed.group(), Some("apps"));
+        assert_eq!(ApiVersion::from(borrowed), owned);
+    }
+
+    #[rstest]
+    #[case("v1", "apps/v1")]
+    #[case("apps/v1beta1", "apps/v1")]
+    #[case("apps/v1", "batch/v1beta1")]
+    fn ord(#[case] lhs: &str, #[case] rhs: &str) {
+        let lhs = ApiVersionRef::from_str_ref(lhs).unwrap();
+        let rhs = ApiVersionRef::from_str_ref(rhs).unwrap();
+
+        assert!(lhs < rhs);
+        assert_eq!(
+            lhs.cmp(&rhs),
+            lhs.to_api_version().cmp(&rhs.to_api_version())
+        );
+    }
+}
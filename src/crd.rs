@@ -0,0 +1,271 @@
+use alloc::{collections::BTreeSet, string::String, vec::Vec};
+use core::cmp::Ordering;
+
+use snafu::Snafu;
+
+use crate::{RawVersion, Version};
+
+#[derive(Debug, PartialEq, Snafu)]
+pub enum CrdValidationError {
+    #[snafu(display("version name {name:?} is not a valid DNS-1035 label"))]
+    InvalidName { name: String },
+
+    #[snafu(display("version name {name:?} is used more than once"))]
+    DuplicateName { name: String },
+
+    #[snafu(display("no version is marked as served"))]
+    NoServedVersion,
+
+    #[snafu(display("no version is marked as storage version"))]
+    NoStorageVersion,
+
+    #[snafu(display("multiple versions are marked as storage version: {names:?}"))]
+    MultipleStorageVersions { names: Vec<String> },
+}
+
+/// A version entry of a CustomResourceDefinition, as found in
+/// `spec.versions` of the CRD.
+///
+/// The name is kept as a plain string, because Kubernetes allows CRD version
+/// names which don't follow the `v<MAJOR>(beta/alpha<LEVEL>)` format.
+///
+/// ### See
+///
+/// - <https://kubernetes.io/docs/tasks/extend-kubernetes/custom-resources/custom-resource-definition-versioning/>
+#[derive(Debug, PartialEq)]
+pub struct CrdVersion {
+    pub name: String,
+    pub served: bool,
+    pub storage: bool,
+}
+
+/// Returns the preferred version among all served versions, using the
+/// Kubernetes version priority (see [`Version::priority_cmp`]). Version names
+/// which can't be parsed have a lower priority than all valid versions and
+/// are ordered alphabetically among themselves. Returns [`None`] if no
+/// version is served.
+pub fn preferred_served_version(versions: &[CrdVersion]) -> Option<&CrdVersion> {
+    versions
+        .iter()
+        .filter(|version| version.served)
+        .max_by(|lhs, rhs| priority_cmp_str(&lhs.name, &rhs.name))
+}
+
+/// Chooses the version which should be marked as the storage version when
+/// generating a CustomResourceDefinition. This is the newest stable version,
+/// or, if there is no stable version, the newest version overall. Newest
+/// refers to the highest Kubernetes priority (see [`Version::priority_cmp`]).
+/// Because stable versions always have a higher priority than beta and alpha
+/// versions, this is the version with the highest priority. Returns [`None`]
+/// if `versions` is empty.
+pub fn choose_storage_version(versions: &[Version]) -> Option<Version> {
+    versions
+        .iter()
+        .max_by(|lhs, rhs| lhs.priority_cmp(rhs))
+        .copied()
+}
+
+/// Validates the versions of a CustomResourceDefinition against the rules
+/// enforced by Kubernetes:
+///
+/// - Every version name must be a valid DNS-1035 label.
+/// - Every version name must be unique.
+/// - At least one version must be served.
+/// - Exactly one version must be the storage version.
+///
+/// All violations are collected and returned at once.
+pub fn validate_crd_versions(versions: &[CrdVersion]) -> Result<(), Vec<CrdValidationError>> {
+    let mut errors = Vec::new();
+    let mut seen = BTreeSet::new();
+
+    for version in versions {
+        if !is_dns_1035_label(&version.name) {
+            errors.push(CrdValidationError::InvalidName {
+                name: version.name.clone(),
+            });
+        }
+
+        if !seen.insert(version.name.as_str()) {
+            errors.push(CrdValidationError::DuplicateName {
+                name: version.name.clone(),
+            });
+        }
+    }
+
+    if !versions.iter().any(|version| version.served) {
+        errors.push(CrdValidationError::NoServedVersion);
+    }
+
+    let storage: Vec<_> = versions
+        .iter()
+        .filter(|version| version.storage)
+        .map(|version| version.name.clone())
+        .collect();
+
+    match storage.len() {
+        0 => errors.push(CrdValidationError::NoStorageVersion),
+        1 => {}
+        _ => errors.push(CrdValidationError::MultipleStorageVersions { names: storage }),
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Returns `true` if `input` is a DNS-1035 label: at most 63 lowercase
+/// alphanumeric characters or `-`, starting with a letter and ending with
+/// an alphanumeric character.
+fn is_dns_1035_label(input: &str) -> bool {
+    let bytes = input.as_bytes();
+
+    matches!(bytes.first(), Some(b'a'..=b'z'))
+        && bytes.len() <= 63
+        && bytes
+            .iter()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || *b == b'-')
+        && bytes.last() != Some(&b'-')
+}
+
+/// Compares two version strings by their Kubernetes priority. For canonical
+/// versions, this mirrors `CompareKubeAwareVersionStrings` from apimachinery,
+/// see [`RawVersion`] for the exact ordering and the differences.
+pub(crate) fn priority_cmp_str(lhs: &str, rhs: &str) -> Ordering {
+    RawVersion::parse_lenient(lhs).cmp(&RawVersion::parse_lenient(rhs))
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    fn crd_versions(input: &[(&str, bool, bool)]) -> Vec<CrdVersion> {
+        input
+            .iter()
+            .map(|(name, served, storage)| CrdVersion {
+                name: name.to_string(),
+                served: *served,
+                storage: *storage,
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case(&[("v1", true, false), ("v1beta1", true, false), ("v2alpha1", true, false)], Some("v1"))]
+    #[case(&[("v1", false, false), ("v1beta1", true, false), ("v1alpha1", true, false)], Some("v1beta1"))]
+    #[case(&[("v2", false, false), ("v1", false, false), ("v1alpha1", true, false)], Some("v1alpha1"))]
+    #[case(&[("foo1", true, false), ("v1alpha1", true, false)], Some("v1alpha1"))]
+    #[case(&[("foo10", true, false), ("foo1", true, false)], Some("foo1"))]
+    #[case(&[("v1", false, false)], None)]
+    #[case(&[], None)]
+    fn preferred_served_version(
+        #[case] input: &[(&str, bool, bool)],
+        #[case] expected: Option<&str>,
+    ) {
+        let versions = crd_versions(input);
+
+        let preferred = super::preferred_served_version(&versions);
+        assert_eq!(preferred.map(|version| version.name.as_str()), expected);
+    }
+
+    #[rstest]
+    #[case(&["v1beta1", "v1", "v2alpha1", "v2"], Some("v2"))]
+    #[case(&["v2beta1", "v1", "v3alpha1"], Some("v1"))]
+    #[case(&["v1beta1", "v2alpha1", "v1beta2"], Some("v1beta2"))]
+    #[case(&["v1alpha1", "v2alpha1"], Some("v2alpha1"))]
+    #[case(&[], None)]
+    fn choose_storage_version(#[case] input: &[&str], #[case] expected: Option<&str>) {
+        let versions: Vec<_> = input
+            .iter()
+            .map(|input| Version::from_str(input).unwrap())
+            .collect();
+
+        let storage = super::choose_storage_version(&versions);
+        assert_eq!(
+            storage.map(|version| version.to_string()).as_deref(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(&[("v1", true, true)])]
+    #[case(&[("v1", true, true), ("v1beta1", true, false), ("v1alpha1", false, false)])]
+    #[case(&[("foo1", true, true)])]
+    fn valid_crd_versions(#[case] input: &[(&str, bool, bool)]) {
+        assert_eq!(validate_crd_versions(&crd_versions(input)), Ok(()));
+    }
+
+    #[rstest]
+    #[case(
+        &[("v1", true, true), ("V2", true, false), ("1beta1", true, false)],
+        vec![
+            CrdValidationError::InvalidName { name: "V2".into() },
+            CrdValidationError::InvalidName { name: "1beta1".into() },
+        ]
+    )]
+    #[case(
+        &[("v1", true, true), ("v1", true, false)],
+        vec![CrdValidationError::DuplicateName { name: "v1".into() }]
+    )]
+    #[case(
+        &[("v1", true, false), ("v1beta1", true, false)],
+        vec![CrdValidationError::NoStorageVersion]
+    )]
+    #[case(
+        &[("v1", true, true), ("v1beta1", true, true)],
+        vec![CrdValidationError::MultipleStorageVersions {
+            names: vec!["v1".into(), "v1beta1".into()],
+        }]
+    )]
+    #[case(
+        &[("v1", false, true)],
+        vec![CrdValidationError::NoServedVersion]
+    )]
+    #[case(
+        &[],
+        vec![CrdValidationError::NoServedVersion, CrdValidationError::NoStorageVersion]
+    )]
+    fn invalid_crd_versions(
+        #[case] input: &[(&str, bool, bool)],
+        #[case] expected: Vec<CrdValidationError>,
+    ) {
+        assert_eq!(validate_crd_versions(&crd_versions(input)), Err(expected));
+    }
+
+    /// The rows of `TestCompareKubeAwareVersionStrings` in
+    /// <https://github.com/kubernetes/apimachinery/blob/master/pkg/version/helpers_test.go>,
+    /// copied verbatim. Like upstream, each row asserts whether `v1` is
+    /// greater than `v2`.
+    #[rstest]
+    #[case("v1", "v1", false)]
+    #[case("v2", "v1", true)]
+    #[case("v10", "v2", true)]
+    #[case("v1", "v2alpha1", true)]
+    #[case("v1", "v2beta1", true)]
+    #[case("v1alpha2", "v1alpha1", true)]
+    #[case("v1beta1", "v2alpha3", true)]
+    #[case("v1alpha10", "v1alpha2", true)]
+    #[case("v1beta10", "v1beta2", true)]
+    #[case("foo", "v1beta2", false)]
+    #[case("bar", "foo", true)]
+    #[case("version1", "version2", true)]
+    #[case("version1", "version10", true)]
+    fn apimachinery_priority_fixture(
+        #[case] v1: &str,
+        #[case] v2: &str,
+        #[case] expected_greater: bool,
+    ) {
+        let ordering = priority_cmp_str(v1, v2);
+        assert_eq!(ordering == Ordering::Greater, expected_greater);
+        assert_eq!(priority_cmp_str(v2, v1), ordering.reverse());
+
+        if let (Ok(lhs), Ok(rhs)) = (Version::from_str(v1), Version::from_str(v2)) {
+            assert_eq!(lhs.priority_cmp(&rhs), ordering);
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{ApiVersion, Version};
+
+/// A discovery entry for a single API version, matching the shape of the
+/// Kubernetes `GroupVersionForDiscovery` type.
+///
+/// With the `serde` feature enabled, this serializes like Kubernetes does,
+/// for example `{"groupVersion":"apps/v1","version":"v1"}`. The `group`
+/// field is only provided for convenience and is not serialized, because
+/// `GroupVersionForDiscovery` doesn't contain it.
+///
+/// ### See
+///
+/// - <https://kubernetes.io/docs/reference/generated/kubernetes-api/v1.29/#groupversionfordiscovery-v1-meta>
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct DiscoveryEntry {
+    /// The group and version in the `GroupVersion` wire form, like `apps/v1`
+    /// or `v1` for the core group.
+    pub group_version: String,
+
+    /// The group, or the empty string for the core group.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub group: String,
+
+    /// The version, like `v1`.
+    pub version: String,
+}
+
+/// A summary of a single API group, matching the shape of the Kubernetes
+/// `APIGroup` type. See [`build_api_group_list`].
+///
+/// With the `serde` feature enabled, this serializes like Kubernetes does,
+/// with `versions` and `preferredVersion` as [`DiscoveryEntry`]s:
+///
+/// ```json
+/// {
+///   "name": "apps",
+///   "versions": [{"groupVersion": "apps/v1", "version": "v1"}],
+///   "preferredVersion": {"groupVersion": "apps/v1", "version": "v1"}
+/// }
+/// ```
+///
+/// ### See
+///
+/// - <https://kubernetes.io/docs/reference/generated/kubernetes-api/v1.29/#apigroup-v1-meta>
+#[derive(Debug, PartialEq)]
+pub struct ApiGroupSummary {
+    /// The name of the group, like `apps`.
+    pub name: String,
+
+    /// The versions of this group, sorted by descending priority.
+    pub versions: Vec<Version>,
+
+    /// The preferred version of this group, which is the version with the
+    /// highest priority.
+    pub preferred: Version,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ApiGroupSummary {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let discovery_entry = |version: Version| {
+            ApiVersion {
+                group: Some(self.name.clone()),
+                version,
+            }
+            .to_discovery_entry()
+        };
+
+        let versions: Vec<_> = self
+            .versions
+            .iter()
+            .map(|version| discovery_entry(*version))
+            .collect();
+
+        let mut state = serializer.serialize_struct("APIGroup", 3)?;
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("versions", &versions)?;
+        state.serialize_field("preferredVersion", &discovery_entry(self.preferred))?;
+        state.end()
+    }
+}
+
+/// Collapses a list of API versions into one [`ApiGroupSummary`] per named
+/// group, like the `groups` of a Kubernetes `APIGroupList`. The core group is
+/// skipped, because Kubernetes serves it separately under `/api` instead of
+/// listing it as a group.
+///
+/// Groups are sorted by name. The versions of each group are deduplicated and
+/// sorted by descending Kubernetes priority (see [`Version::priority_cmp`]),
+/// and the first one is the preferred version.
+pub fn build_api_group_list(items: &[ApiVersion]) -> Vec<ApiGroupSummary> {
+    let mut groups: BTreeMap<&str, Vec<&ApiVersion>> = BTreeMap::new();
+
+    for item in items {
+        if let Some(name) = item.core_normalized_group() {
+            groups.entry(name).or_default().push(item);
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|(name, items)| {
+            let mut versions: Vec<_> = items.into_iter().map(|item| item.version).collect();
+            versions.sort_by(|lhs, rhs| rhs.priority_cmp(lhs));
+            versions.dedup();
+
+            ApiGroupSummary {
+                name: name.to_string(),
+                preferred: versions[0],
+                versions,
+            }
+        })
+        .collect()
+}
+
+impl ApiVersion {
+    /// Returns the discovery entry of this API version.
+    pub fn to_discovery_entry(&self) -> DiscoveryEntry {
+        DiscoveryEntry {
+            group_version: self.to_group_version_string(),
+            group: self.group_or_core().to_string(),
+            version: self.version.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("apps/v1", "apps/v1", "apps", "v1")]
+    #[case("v1", "v1", "", "v1")]
+    fn to_discovery_entry(
+        #[case] input: &str,
+        #[case] group_version: &str,
+        #[case] group: &str,
+        #[case] version: &str,
+    ) {
+        let entry = ApiVersion::from_str(input).unwrap().to_discovery_entry();
+        assert_eq!(
+            entry,
+            DiscoveryEntry {
+                group_version: group_version.to_string(),
+                group: group.to_string(),
+                version: version.to_string(),
+            }
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_discovery_entry() {
+        let entry = ApiVersion::from_str("apps/v1")
+            .unwrap()
+            .to_discovery_entry();
+        assert_eq!(
+            serde_json::to_string(&entry).unwrap(),
+            r#"{"groupVersion":"apps/v1","version":"v1"}"#
+        );
+    }
+
+    #[test]
+    fn build_api_group_list() {
+        let items: Vec<_> = [
+            "apps/v1beta1",
+            "v1",
+            "apps/v1",
+            "batch/v1beta1",
+            "apps/v1beta2",
+            "apps/v1",
+            "autoscaling/v2",
+        ]
+        .iter()
+        .map(|input| ApiVersion::from_str(input).unwrap())
+        .collect();
+
+        let version = |input| Version::from_str(input).unwrap();
+
+        assert_eq!(
+            super::build_api_group_list(&items),
+            [
+                ApiGroupSummary {
+                    name: "apps".to_string(),
+                    versions: vec![version("v1"), version("v1beta2"), version("v1beta1")],
+                    preferred: version("v1"),
+                },
+                ApiGroupSummary {
+                    name: "autoscaling".to_string(),
+                    versions: vec![version("v2")],
+                    preferred: version("v2"),
+                },
+                ApiGroupSummary {
+                    name: "batch".to_string(),
+                    versions: vec![version("v1beta1")],
+                    preferred: version("v1beta1"),
+                },
+            ]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_api_group_summary() {
+        let items: Vec<_> = ["apps/v1beta1", "apps/v1"]
+            .iter()
+            .map(|input| ApiVersion::from_str(input).unwrap())
+            .collect();
+
+        let groups = super::build_api_group_list(&items);
+        assert_eq!(
+            serde_json::to_value(&groups).unwrap(),
+            serde_json::json!([{
+                "name": "apps",
+                "versions": [
+                    {"groupVersion": "apps/v1", "version": "v1"},
+                    {"groupVersion": "apps/v1beta1", "version": "v1beta1"},
+                ],
+                "preferredVersion": {"groupVersion": "apps/v1", "version": "v1"},
+            }])
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use alloc::vec::Vec;
+
+use snafu::Snafu;
+
+use crate::{
+    ApiVersionParseError, CrdValidationError, GroupValidationError, GroupVersionKindParseError,
+    KubeMinorParseError, ParseLevelError, VersionListParseError, VersionParseError,
+};
+
+/// Unifies all errors of this crate, which makes it possible to use `?` on
+/// different fallible operations in a single function.
+///
+/// Each variant wraps the specific error as its source, so callers can still
+/// match on the detailed variants.
+#[derive(Debug, PartialEq, Snafu)]
+pub enum Error {
+    #[snafu(context(false), display("failed to parse version"))]
+    ParseVersion { source: VersionParseError },
+
+    #[snafu(context(false), display("failed to parse version list"))]
+    ParseVersionList { source: VersionListParseError },
+
+    #[snafu(context(false), display("failed to parse version level"))]
+    ParseLevel { source: ParseLevelError },
+
+    #[snafu(context(false), display("failed to parse API version"))]
+    ParseApiVersion { source: ApiVersionParseError },
+
+    #[snafu(context(false), display("failed to parse group, version and kind"))]
+    ParseGroupVersionKind { source: GroupVersionKindParseError },
+
+    #[snafu(context(false), display("failed to parse Kubernetes release"))]
+    ParseKubeMinor { source: KubeMinorParseError },
+
+    #[snafu(context(false), display("invalid group"))]
+    InvalidGroup { source: GroupValidationError },
+
+    /// All violations found by
+    /// [`validate_crd_versions`][crate::validate_crd_versions].
+    #[snafu(display("invalid CRD versions, found {} violations", errors.len()))]
+    InvalidCrd { errors: Vec<CrdValidationError> },
+}
+
+impl From<Vec<CrdValidationError>> for Error {
+    fn from(errors: Vec<CrdValidationError>) -> Self {
+        Self::InvalidCrd { errors }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{error::Error as _, str::FromStr};
+
+    use crate::{
+        validate_crd_versions, validate_group, ApiVersion, CrdVersion, GroupVersionKind, KubeMinor,
+        Level, Version,
+    };
+
+    use super::*;
+
+    fn parse_all(version: &str, api_version: &str, gvk: &str) -> Result<(), Error> {
+        Version::from_str(version)?;
+        ApiVersion::from_str(api_version)?;
+        GroupVersionKind::from_str(gvk)?;
+        KubeMinor::from_str("1.30")?;
+        Level::from_str("beta1")?;
+
+        Ok(())
+    }
+
+    fn validate_all(group: &str, storage: bool) -> Result<(), Error> {
+        validate_group(group)?;
+        validate_crd_versions(&[CrdVersion {
+            name: "v1".to_string(),
+            served: true,
+            storage,
+        }])?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn question_mark() {
+        assert_eq!(parse_all("v1", "apps/v1", "apps/v1/Deployment"), Ok(()));
+        assert_eq!(validate_all("apps", true), Ok(()));
+    }
+
+    #[test]
+    fn question_mark_invalid_group() {
+        let err = validate_all("Apps", true).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::InvalidGroup {
+                source: GroupValidationError::InvalidCharacter { .. }
+            }
+        ));
+    }
+
+    #[test]
+    fn question_mark_invalid_crd() {
+        assert_eq!(
+            validate_all("apps", false),
+            Err(Error::InvalidCrd {
+                errors: vec![CrdValidationError::NoStorageVersion]
+            })
+        );
+    }
+
+    #[test]
+    fn source_chain() {
+        let err = parse_all("v1", "apps/v1", "apps/v01/Deployment").unwrap_err();
+
+        assert!(matches!(
+            err,
+            Error::ParseGroupVersionKind {
+                source: GroupVersionKindParseError::ParseApiVersion {
+                    source: ApiVersionParseError::ParseVersion { .. }
+                }
+            }
+        ));
+
+        let source = err.source().expect("must have a source");
+        assert_eq!(source.to_string(), "failed to parse API version");
+    }
+
+    #[test]
+    fn specific_from() {
+        let err = Level::from_str("gamma1").unwrap_err();
+        assert_eq!(
+            VersionParseError::from(err),
+            VersionParseError::ParseLevel {
+                source: Level::from_str("gamma1").unwrap_err()
+            }
+        );
+    }
+}
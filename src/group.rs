@@ -0,0 +1,157 @@
+use alloc::string::String;
+
+use snafu::{ensure, Snafu};
+
+#[derive(Debug, PartialEq, Snafu)]
+pub enum GroupValidationError {
+    #[snafu(display(
+        "invalid character {character:?} at index {index} in group label {label:?}, expected lower case alphanumeric characters or '-'"
+    ))]
+    InvalidCharacter {
+        label: String,
+        character: char,
+        index: usize,
+    },
+
+    #[snafu(display("group is {length} characters long, exceeding the maximum of 253"))]
+    TooLong { length: usize },
+
+    #[snafu(display("empty group label at index {index}"))]
+    EmptyLabel { index: usize },
+
+    #[snafu(display(
+        "group label {label:?} is {length} characters long, exceeding the maximum of 63"
+    ))]
+    LabelTooLong { label: String, length: usize },
+
+    #[snafu(display("group label {label:?} must start and end with an alphanumeric character"))]
+    InvalidLabelBoundary { label: String },
+}
+
+/// Validates that `group` is a valid API group, which is a DNS subdomain of
+/// at most 253 characters. Every dot-separated label is checked individually,
+/// so that errors can name the failing label and character. A label must be
+/// a DNS label: between 1 and 63 lower case alphanumeric characters or `-`,
+/// starting and ending with an alphanumeric character. Reported indices are
+/// byte offsets within `group`.
+pub fn validate_group(group: &str) -> Result<(), GroupValidationError> {
+    ensure!(
+        group.len() <= 253,
+        TooLongSnafu {
+            length: group.len()
+        }
+    );
+
+    let mut offset = 0;
+    for label in group.split('.') {
+        validate_label(label, offset)?;
+        offset += label.len() + 1;
+    }
+
+    Ok(())
+}
+
+fn validate_label(label: &str, offset: usize) -> Result<(), GroupValidationError> {
+    ensure!(!label.is_empty(), EmptyLabelSnafu { index: offset });
+    ensure!(
+        label.len() <= 63,
+        LabelTooLongSnafu {
+            label,
+            length: label.len()
+        }
+    );
+
+    for (index, character) in label.char_indices() {
+        ensure!(
+            matches!(character, 'a'..='z' | '0'..='9' | '-'),
+            InvalidCharacterSnafu {
+                label,
+                character,
+                index: offset + index,
+            }
+        );
+    }
+
+    ensure!(
+        !label.starts_with('-') && !label.ends_with('-'),
+        InvalidLabelBoundarySnafu { label }
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("apps")]
+    #[case("certificates.k8s.io")]
+    #[case("my-group.example.com")]
+    #[case("a.b.c")]
+    #[case("a1.2b.c-d")]
+    fn valid_group(#[case] input: &str) {
+        assert_eq!(validate_group(input), Ok(()));
+    }
+
+    #[rstest]
+    #[case("apps.BAD.io", "BAD", 'B', 5)]
+    #[case("Apps", "Apps", 'A', 0)]
+    #[case("apps.example_group.io", "example_group", '_', 12)]
+    #[case("äpps", "äpps", 'ä', 0)]
+    fn invalid_group_character(
+        #[case] input: &str,
+        #[case] label: &str,
+        #[case] character: char,
+        #[case] index: usize,
+    ) {
+        assert_eq!(
+            validate_group(input),
+            Err(GroupValidationError::InvalidCharacter {
+                label: label.to_string(),
+                character,
+                index,
+            })
+        );
+    }
+
+    #[rstest]
+    #[case("-bad", GroupValidationError::InvalidLabelBoundary { label: "-bad".into() })]
+    #[case("bad-", GroupValidationError::InvalidLabelBoundary { label: "bad-".into() })]
+    #[case("apps.-bad.io", GroupValidationError::InvalidLabelBoundary { label: "-bad".into() })]
+    #[case("a..b", GroupValidationError::EmptyLabel { index: 2 })]
+    #[case(".apps", GroupValidationError::EmptyLabel { index: 0 })]
+    #[case("apps.", GroupValidationError::EmptyLabel { index: 5 })]
+    #[case("", GroupValidationError::EmptyLabel { index: 0 })]
+    fn invalid_group_structure(#[case] input: &str, #[case] error: GroupValidationError) {
+        assert_eq!(validate_group(input), Err(error));
+    }
+
+    #[test]
+    fn label_length() {
+        let label = "a".repeat(63);
+        assert_eq!(validate_group(&format!("{label}.io")), Ok(()));
+
+        let label = "a".repeat(64);
+        assert_eq!(
+            validate_group(&format!("{label}.io")),
+            Err(GroupValidationError::LabelTooLong { label, length: 64 })
+        );
+    }
+
+    #[test]
+    fn group_length() {
+        let label = "a".repeat(63);
+        let group = format!("{label}.{label}.{label}.{}", "a".repeat(61));
+        assert_eq!(group.len(), 253);
+        assert_eq!(validate_group(&group), Ok(()));
+
+        let group = format!("{group}a");
+        assert_eq!(
+            validate_group(&group),
+            Err(GroupValidationError::TooLong { length: 254 })
+        );
+    }
+}
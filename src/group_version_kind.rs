@@ -0,0 +1,139 @@
+use alloc::string::String;
+use core::{fmt::Display, str::FromStr};
+
+use snafu::{ensure, OptionExt, ResultExt, Snafu};
+
+use crate::{ApiVersion, ApiVersionParseError};
+
+#[derive(Debug, PartialEq, Snafu)]
+pub enum GroupVersionKindParseError {
+    #[snafu(display("missing kind, expected <GROUP>/<VERSION>/<KIND> or <VERSION>/<KIND>"))]
+    MissingKind,
+
+    #[snafu(display(
+        "invalid kind {kind:?}, expected an alphanumeric name starting with an upper case letter"
+    ))]
+    InvalidKind { kind: String },
+
+    #[snafu(display("failed to parse API version"))]
+    ParseApiVersion { source: ApiVersionParseError },
+}
+
+impl From<ApiVersionParseError> for GroupVersionKindParseError {
+    fn from(source: ApiVersionParseError) -> Self {
+        Self::ParseApiVersion { source }
+    }
+}
+
+/// A Kubernetes kind together with its [`ApiVersion`], with the
+/// `(<GROUP>/)<VERSION>/<KIND>` format, for example `apps/v1/Deployment` or
+/// `v1/Pod`.
+///
+/// The kind must be a non-empty alphanumeric name starting with an upper case
+/// letter, like `Deployment` or `CustomResourceDefinition`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GroupVersionKind {
+    api_version: ApiVersion,
+    kind: String,
+}
+
+impl FromStr for GroupVersionKind {
+    type Err = GroupVersionKindParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (api_version, kind) = input.rsplit_once('/').context(MissingKindSnafu)?;
+        let api_version = ApiVersion::from_str(api_version).context(ParseApiVersionSnafu)?;
+
+        Self::new(api_version, kind)
+    }
+}
+
+impl GroupVersionKind {
+    /// Creates a new [`GroupVersionKind`], validating the `kind`.
+    pub fn new(
+        api_version: ApiVersion,
+        kind: impl Into<String>,
+    ) -> Result<Self, GroupVersionKindParseError> {
+        let kind = kind.into();
+
+        ensure!(is_valid_kind(&kind), InvalidKindSnafu { kind });
+        Ok(Self { api_version, kind })
+    }
+
+    /// Returns the API version, like `apps/v1` for `apps/v1/Deployment`.
+    pub fn group_version(&self) -> &ApiVersion {
+        &self.api_version
+    }
+
+    /// Returns the kind, like `Deployment` for `apps/v1/Deployment`.
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+}
+
+impl Display for GroupVersionKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}/{}", self.api_version, self.kind)
+    }
+}
+
+fn is_valid_kind(kind: &str) -> bool {
+    kind.starts_with(|c: char| c.is_ascii_uppercase())
+        && kind.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("apps/v1/Deployment", "apps/v1", "Deployment")]
+    #[case(
+        "certificates.k8s.io/v1beta1/CertificateSigningRequest",
+        "certificates.k8s.io/v1beta1",
+        "CertificateSigningRequest"
+    )]
+    #[case("v1/Pod", "v1", "Pod")]
+    fn valid_group_version_kind(
+        #[case] input: &str,
+        #[case] api_version: &str,
+        #[case] kind: &str,
+    ) {
+        let gvk = GroupVersionKind::from_str(input).unwrap();
+
+        assert_eq!(gvk.group_version().to_string(), api_version);
+        assert_eq!(gvk.kind(), kind);
+        assert_eq!(gvk.to_string(), input);
+    }
+
+    #[rstest]
+    #[case("apps/v1/deployment", "deployment")]
+    #[case("apps/v1/", "")]
+    #[case("v1/Pod-1", "Pod-1")]
+    fn invalid_kind(#[case] input: &str, #[case] kind: &str) {
+        let err = GroupVersionKind::from_str(input).unwrap_err();
+        assert_eq!(
+            err,
+            GroupVersionKindParseError::InvalidKind {
+                kind: kind.to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn missing_kind() {
+        let err = GroupVersionKind::from_str("Pod").unwrap_err();
+        assert_eq!(err, GroupVersionKindParseError::MissingKind);
+    }
+
+    #[test]
+    fn invalid_api_version() {
+        let err = GroupVersionKind::from_str("apps/v1gamma1/Deployment").unwrap_err();
+        assert!(matches!(
+            err,
+            GroupVersionKindParseError::ParseApiVersion { .. }
+        ));
+    }
+}
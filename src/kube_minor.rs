@@ -0,0 +1,114 @@
+use core::{fmt::Display, num::ParseIntError, str::FromStr};
+
+use snafu::{ensure, OptionExt, ResultExt, Snafu};
+
+use crate::version::has_leading_zero;
+
+#[derive(Debug, PartialEq, Snafu)]
+pub enum KubeMinorParseError {
+    #[snafu(display(
+        "invalid Kubernetes release format, expected <MAJOR>.<MINOR> without signs or leading zeros"
+    ))]
+    InvalidFormat,
+
+    #[snafu(display("failed to parse major release number"))]
+    ParseMajor { source: ParseIntError },
+
+    #[snafu(display("failed to parse minor release number"))]
+    ParseMinor { source: ParseIntError },
+}
+
+/// A Kubernetes (cluster) minor release with the `<MAJOR>.<MINOR>` format,
+/// for example `1.25`.
+///
+/// This is **not** an API version. It identifies the Kubernetes release a
+/// cluster runs and can be used to gate behaviour on releases, like
+/// "Kubernetes >= 1.25".
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct KubeMinor(pub u16, pub u16);
+
+impl FromStr for KubeMinor {
+    type Err = KubeMinorParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (major, minor) = input.split_once('.').context(InvalidFormatSnafu)?;
+
+        // Signs and leading zeros are rejected, so that every parsed release
+        // formats back to its input.
+        ensure!(
+            is_canonical_number(major) && is_canonical_number(minor),
+            InvalidFormatSnafu
+        );
+
+        let major = major.parse::<u16>().context(ParseMajorSnafu)?;
+        let minor = minor.parse::<u16>().context(ParseMinorSnafu)?;
+
+        Ok(Self(major, minor))
+    }
+}
+
+/// Returns `true` if `digits` only consists of ASCII digits, without a
+/// leading zero.
+fn is_canonical_number(digits: &str) -> bool {
+    digits.bytes().all(|b| b.is_ascii_digit()) && !has_leading_zero(digits)
+}
+
+impl Display for KubeMinor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}", self.0, self.1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cmp::Ordering;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("1.22", KubeMinor(1, 22))]
+    #[case("1.25", KubeMinor(1, 25))]
+    #[case("0.0", KubeMinor(0, 0))]
+    #[case("1.0", KubeMinor(1, 0))]
+    fn valid_kube_minor(#[case] input: &str, #[case] expected: KubeMinor) {
+        let minor = KubeMinor::from_str(input).unwrap();
+        assert_eq!(minor, expected);
+        assert_eq!(minor.to_string(), input);
+    }
+
+    #[rstest]
+    #[case("1", KubeMinorParseError::InvalidFormat)]
+    #[case("", KubeMinorParseError::InvalidFormat)]
+    #[case("+1.25", KubeMinorParseError::InvalidFormat)]
+    #[case("1.+25", KubeMinorParseError::InvalidFormat)]
+    #[case("01.25", KubeMinorParseError::InvalidFormat)]
+    #[case("01.025", KubeMinorParseError::InvalidFormat)]
+    #[case("1.025", KubeMinorParseError::InvalidFormat)]
+    #[case("1.-1", KubeMinorParseError::InvalidFormat)]
+    fn invalid_kube_minor(#[case] input: &str, #[case] error: KubeMinorParseError) {
+        let err = KubeMinor::from_str(input).unwrap_err();
+        assert_eq!(err, error)
+    }
+
+    #[rstest]
+    #[case("1.22", "1.25", Ordering::Less)]
+    #[case("1.25", "1.22", Ordering::Greater)]
+    #[case("1.25", "1.25", Ordering::Equal)]
+    #[case("2.0", "1.25", Ordering::Greater)]
+    fn ord_kube_minor(#[case] input: &str, #[case] other: &str, #[case] expected: Ordering) {
+        let input = KubeMinor::from_str(input).unwrap();
+        let other = KubeMinor::from_str(other).unwrap();
+        assert_eq!(input.cmp(&other), expected)
+    }
+
+    #[test]
+    fn copy_and_hash() {
+        let minor = KubeMinor(1, 30);
+        let copy = minor;
+
+        let set: std::collections::HashSet<_> = [minor, copy].into_iter().collect();
+        assert_eq!(set.len(), 1);
+    }
+}
@@ -1,4 +1,4 @@
-use std::{
+use core::{
     cmp::Ordering,
     fmt::Display,
     num::ParseIntError,
@@ -6,14 +6,9 @@ use std::{
     str::FromStr,
 };
 
-use lazy_static::lazy_static;
-use regex::Regex;
-use snafu::{OptionExt, ResultExt, Snafu};
+use snafu::{ensure, ResultExt, Snafu};
 
-lazy_static! {
-    static ref LEVEL_REGEX: Regex =
-        Regex::new(r"^(?P<identifier>[a-z]+)(?P<version>\d+)$").unwrap();
-}
+use crate::version::{has_leading_zero, split_while};
 
 #[derive(Debug, PartialEq, Snafu)]
 pub enum ParseLevelError {
@@ -28,7 +23,8 @@ pub enum ParseLevelError {
 }
 
 /// A minor Kubernetes resource version with the `beta/alpha<VERSION>` format.
-#[derive(Debug, PartialEq)]
+/// Levels are [`Copy`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Level {
     Beta(u64),
     Alpha(u64),
@@ -38,19 +34,16 @@ impl FromStr for Level {
     type Err = ParseLevelError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let captures = LEVEL_REGEX.captures(input).context(InvalidFormatSnafu)?;
-
-        let identifier = captures
-            .name("identifier")
-            .expect("internal error: check that the correct match label is specified")
-            .as_str();
+        let (identifier, version) = split_while(input, u8::is_ascii_lowercase);
+        ensure!(
+            !identifier.is_empty()
+                && !version.is_empty()
+                && version.bytes().all(|b| b.is_ascii_digit()),
+            InvalidFormatSnafu
+        );
 
-        let version = captures
-            .name("version")
-            .expect("internal error: check that the correct match label is specified")
-            .as_str()
-            .parse::<u64>()
-            .context(ParseVersionSnafu)?;
+        ensure!(!has_leading_zero(version), InvalidFormatSnafu);
+        let version = version.parse::<u64>().context(ParseVersionSnafu)?;
 
         match identifier {
             "alpha" => Ok(Self::Alpha(version)),
@@ -60,6 +53,49 @@ impl FromStr for Level {
     }
 }
 
+impl Level {
+    /// Returns `true` if the level number equals `n`, regardless of whether
+    /// this is a beta or an alpha level. `Level::Beta(3).eq_number(3)` and
+    /// `Level::Alpha(3).eq_number(3)` both return `true`.
+    pub fn eq_number(&self, n: u64) -> bool {
+        self.number() == n
+    }
+
+    /// Compares the level number with `n`, regardless of whether this is a
+    /// beta or an alpha level.
+    pub fn cmp_number(&self, n: u64) -> Ordering {
+        self.number().cmp(&n)
+    }
+
+    /// Adds `rhs` to the level number, returning [`None`] if the addition
+    /// overflows. `Level::Beta(u64::MAX).checked_add(1)` returns [`None`].
+    pub fn checked_add(self, rhs: u64) -> Option<Level> {
+        self.number().checked_add(rhs).map(|n| self.with_number(n))
+    }
+
+    /// Subtracts `rhs` from the level number, returning [`None`] if the
+    /// subtraction underflows. `Level::Beta(0).checked_sub(1)` returns
+    /// [`None`].
+    pub fn checked_sub(self, rhs: u64) -> Option<Level> {
+        self.number().checked_sub(rhs).map(|n| self.with_number(n))
+    }
+
+    /// Returns the level number, like `3` for `beta3`.
+    pub(crate) fn number(&self) -> u64 {
+        match self {
+            Level::Beta(n) | Level::Alpha(n) => *n,
+        }
+    }
+
+    /// Returns a level of the same kind with the level number `n`.
+    fn with_number(self, n: u64) -> Self {
+        match self {
+            Level::Beta(_) => Level::Beta(n),
+            Level::Alpha(_) => Level::Alpha(n),
+        }
+    }
+}
+
 impl PartialOrd for Level {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         match self {
@@ -75,6 +111,8 @@ impl PartialOrd for Level {
     }
 }
 
+/// Adds to the level number. The addition saturates at [`u64::MAX`] instead
+/// of overflowing, use [`Level::checked_add`] to detect an overflow.
 impl<T> Add<T> for Level
 where
     T: Into<u64>,
@@ -83,8 +121,8 @@ where
 
     fn add(self, rhs: T) -> Self::Output {
         match self {
-            Level::Beta(b) => Level::Beta(b + rhs.into()),
-            Level::Alpha(a) => Level::Alpha(a + rhs.into()),
+            Level::Beta(b) => Level::Beta(b.saturating_add(rhs.into())),
+            Level::Alpha(a) => Level::Alpha(a.saturating_add(rhs.into())),
         }
     }
 }
@@ -95,12 +133,14 @@ where
 {
     fn add_assign(&mut self, rhs: T) {
         match self {
-            Level::Beta(b) => *b + rhs.into(),
-            Level::Alpha(a) => *a + rhs.into(),
+            Level::Beta(b) => *b = b.saturating_add(rhs.into()),
+            Level::Alpha(a) => *a = a.saturating_add(rhs.into()),
         };
     }
 }
 
+/// Subtracts from the level number. The subtraction saturates at `0` instead
+/// of underflowing, use [`Level::checked_sub`] to detect an underflow.
 impl<T> Sub<T> for Level
 where
     T: Into<u64>,
@@ -109,8 +149,8 @@ where
 
     fn sub(self, rhs: T) -> Self::Output {
         match self {
-            Level::Beta(b) => Level::Beta(b - rhs.into()),
-            Level::Alpha(a) => Level::Alpha(a - rhs.into()),
+            Level::Beta(b) => Level::Beta(b.saturating_sub(rhs.into())),
+            Level::Alpha(a) => Level::Alpha(a.saturating_sub(rhs.into())),
         }
     }
 }
@@ -121,14 +161,14 @@ where
 {
     fn sub_assign(&mut self, rhs: T) {
         match self {
-            Level::Beta(b) => *b - rhs.into(),
-            Level::Alpha(a) => *a - rhs.into(),
+            Level::Beta(b) => *b = b.saturating_sub(rhs.into()),
+            Level::Alpha(a) => *a = a.saturating_sub(rhs.into()),
         };
     }
 }
 
 impl Display for Level {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Level::Beta(beta) => write!(f, "beta{}", beta),
             Level::Alpha(alpha) => write!(f, "alpha{}", alpha),
@@ -136,6 +176,32 @@ impl Display for Level {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Level {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let n = u.arbitrary()?;
+
+        Ok(if u.arbitrary()? {
+            Level::Beta(n)
+        } else {
+            Level::Alpha(n)
+        })
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Level {
+    /// Returns a [`proptest`] strategy generating beta and alpha levels.
+    pub fn arb() -> impl proptest::strategy::Strategy<Value = Level> {
+        use proptest::prelude::*;
+
+        prop_oneof![
+            any::<u64>().prop_map(Level::Beta),
+            any::<u64>().prop_map(Level::Alpha),
+        ]
+    }
+}
+
 #[cfg(test)]
 mod test {
     use rstest::rstest;
@@ -154,4 +220,118 @@ mod test {
     fn partial_ord_level(#[case] input: Level, #[case] other: Level, #[case] expected: Ordering) {
         assert_eq!(input.partial_cmp(&other), Some(expected))
     }
+
+    #[test]
+    fn copy_semantics() {
+        let level = Level::Beta(1);
+        let next = level + 1u64;
+
+        assert_eq!(level, Level::Beta(1));
+        assert_eq!(next, Level::Beta(2));
+    }
+
+    #[test]
+    fn add_assign() {
+        let mut level = Level::Beta(1);
+        level += 1u64;
+        assert_eq!(level, Level::Beta(2));
+
+        let mut level = Level::Alpha(1);
+        level += 2u64;
+        assert_eq!(level, Level::Alpha(3));
+    }
+
+    #[test]
+    fn sub_assign() {
+        let mut level = Level::Beta(2);
+        level -= 1u64;
+        assert_eq!(level, Level::Beta(1));
+
+        let mut level = Level::Alpha(3);
+        level -= 2u64;
+        assert_eq!(level, Level::Alpha(1));
+    }
+
+    #[rstest]
+    #[case(Level::Beta(1), 1, Some(Level::Beta(2)))]
+    #[case(Level::Alpha(u64::MAX), 0, Some(Level::Alpha(u64::MAX)))]
+    #[case(Level::Beta(u64::MAX), 1, None)]
+    fn checked_add(#[case] input: Level, #[case] rhs: u64, #[case] expected: Option<Level>) {
+        assert_eq!(input.checked_add(rhs), expected)
+    }
+
+    #[rstest]
+    #[case(Level::Beta(2), 1, Some(Level::Beta(1)))]
+    #[case(Level::Alpha(1), 1, Some(Level::Alpha(0)))]
+    #[case(Level::Beta(0), 1, None)]
+    fn checked_sub(#[case] input: Level, #[case] rhs: u64, #[case] expected: Option<Level>) {
+        assert_eq!(input.checked_sub(rhs), expected)
+    }
+
+    #[test]
+    fn saturating_operators() {
+        assert_eq!(Level::Beta(0) - 1u64, Level::Beta(0));
+        assert_eq!(Level::Alpha(u64::MAX) + 1u64, Level::Alpha(u64::MAX));
+
+        let mut level = Level::Beta(0);
+        level -= 1u64;
+        assert_eq!(level, Level::Beta(0));
+
+        let mut level = Level::Beta(u64::MAX);
+        level += 1u64;
+        assert_eq!(level, Level::Beta(u64::MAX));
+    }
+
+    #[rstest]
+    #[case(Level::Beta(3), 3, true)]
+    #[case(Level::Alpha(3), 3, true)]
+    #[case(Level::Beta(3), 2, false)]
+    fn eq_number(#[case] input: Level, #[case] n: u64, #[case] expected: bool) {
+        assert_eq!(input.eq_number(n), expected)
+    }
+
+    #[rstest]
+    #[case(Level::Alpha(1), 2, Ordering::Less)]
+    #[case(Level::Alpha(2), 2, Ordering::Equal)]
+    #[case(Level::Beta(3), 2, Ordering::Greater)]
+    fn cmp_number(#[case] input: Level, #[case] n: u64, #[case] expected: Ordering) {
+        assert_eq!(input.cmp_number(n), expected)
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_level() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let mut u = Unstructured::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+
+        while !u.is_empty() {
+            let level = Level::arbitrary(&mut u).unwrap();
+            assert_eq!(Level::from_str(&level.to_string()), Ok(level));
+        }
+    }
+
+    #[rstest]
+    #[case("beta1", Ok(Level::Beta(1)))]
+    #[case("alpha10", Ok(Level::Alpha(10)))]
+    #[case("beta0", Ok(Level::Beta(0)))]
+    #[case("gamma1", Err(ParseLevelError::UnknownIdentifier))]
+    #[case("beta", Err(ParseLevelError::InvalidFormat))]
+    #[case("1", Err(ParseLevelError::InvalidFormat))]
+    #[case("", Err(ParseLevelError::InvalidFormat))]
+    #[case("beta1x", Err(ParseLevelError::InvalidFormat))]
+    #[case("Beta1", Err(ParseLevelError::InvalidFormat))]
+    #[case("beta-1", Err(ParseLevelError::InvalidFormat))]
+    #[case("betä1", Err(ParseLevelError::InvalidFormat))]
+    fn from_str(#[case] input: &str, #[case] expected: Result<Level, ParseLevelError>) {
+        assert_eq!(Level::from_str(input), expected);
+    }
+
+    #[rstest]
+    #[case("beta99999999999999999999")]
+    #[case("alpha18446744073709551616")]
+    fn level_version_overflow(#[case] input: &str) {
+        let err = Level::from_str(input).unwrap_err();
+        assert!(matches!(err, ParseLevelError::ParseVersion { .. }));
+    }
 }
@@ -1,7 +1,37 @@
+// Tests always link std for the test harness, so that they also run without
+// the std feature.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
 mod api_version;
+mod api_version_ref;
+mod crd;
+mod discovery;
+mod error;
+mod group;
+mod group_version_kind;
+mod kube_minor;
 mod level;
+mod parse_options;
+mod preserved_version;
+mod raw_version;
+mod stability;
+#[cfg(test)]
+mod test_support;
 mod version;
 
 pub use api_version::*;
+pub use api_version_ref::*;
+pub use crd::*;
+pub use discovery::*;
+pub use error::*;
+pub use group::*;
+pub use group_version_kind::*;
+pub use kube_minor::*;
 pub use level::*;
+pub use parse_options::*;
+pub use preserved_version::*;
+pub use raw_version::*;
+pub use stability::*;
 pub use version::*;
@@ -0,0 +1,106 @@
+use alloc::borrow::Cow;
+
+/// Options to customize how [`Version`][crate::Version]s and
+/// [`ApiVersion`][crate::ApiVersion]s are parsed.
+/// [`FromStr`][core::str::FromStr] implementations use the
+/// [default options][ParseOptions::default], which are strict.
+#[derive(Debug, PartialEq)]
+pub struct ParseOptions {
+    /// The maximum number of dot-separated labels a group may consist of.
+    /// Defaults to `10`.
+    pub max_group_labels: usize,
+
+    /// Whether the literal group `core`, like in `core/v1`, is normalized to
+    /// the core group. If disabled, `core` is treated like any other named
+    /// group. Defaults to `false`.
+    pub normalize_core_group: bool,
+
+    /// Whether a single pair of matching surrounding quotes, like in `"v1"`
+    /// or `'apps/v1'`, is stripped before parsing. Defaults to `false`.
+    pub strip_quotes: bool,
+
+    /// Whether a leading UTF-8 byte order mark (`U+FEFF`) is stripped before
+    /// parsing. Defaults to `false`.
+    pub strip_bom: bool,
+
+    /// Whether trailing `\r` and `\n` characters, as found at the end of
+    /// lines in files, are stripped before parsing. Defaults to `false`.
+    pub strip_line_endings: bool,
+
+    /// Whether ASCII upper case letters are converted to lower case before
+    /// parsing, like `Apps/V1Beta1` to `apps/v1beta1`. Kubernetes requires
+    /// lower case, but some tooling emits mixed case. Defaults to `false`.
+    pub lowercase: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            max_group_labels: 10,
+            normalize_core_group: false,
+            strip_quotes: false,
+            strip_bom: false,
+            strip_line_endings: false,
+            lowercase: false,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// Returns options which accept common deviations from the canonical
+    /// format and normalize them:
+    ///
+    /// - The group `core` is normalized to the core group.
+    /// - A single pair of matching surrounding quotes is stripped.
+    /// - A leading byte order mark is stripped.
+    /// - Trailing line endings are stripped.
+    pub fn lenient() -> Self {
+        Self {
+            normalize_core_group: true,
+            strip_quotes: true,
+            strip_bom: true,
+            strip_line_endings: true,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the default options with [`ParseOptions::lowercase`] enabled.
+    pub fn lowercase() -> Self {
+        Self {
+            lowercase: true,
+            ..Default::default()
+        }
+    }
+
+    /// Applies the enabled input normalizations to `input`. Only allocates if
+    /// upper case letters need to be converted.
+    pub(crate) fn normalize<'a>(&self, input: &'a str) -> Cow<'a, str> {
+        let mut input = input;
+
+        if self.strip_bom {
+            input = input.strip_prefix('\u{feff}').unwrap_or(input);
+        }
+
+        if self.strip_line_endings {
+            input = input.trim_end_matches(['\r', '\n']);
+        }
+
+        if self.strip_quotes {
+            input = strip_quotes(input);
+        }
+
+        if self.lowercase && input.bytes().any(|b| b.is_ascii_uppercase()) {
+            return Cow::Owned(input.to_ascii_lowercase());
+        }
+
+        Cow::Borrowed(input)
+    }
+}
+
+/// Strips a single pair of matching surrounding single or double quotes.
+fn strip_quotes(input: &str) -> &str {
+    ['"', '\'']
+        .into_iter()
+        .find_map(|quote| input.strip_prefix(quote)?.strip_suffix(quote))
+        .unwrap_or(input)
+}
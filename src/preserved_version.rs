@@ -0,0 +1,106 @@
+use core::fmt::Display;
+
+use crate::{ParseOptions, Version, VersionParseError};
+
+/// A parsed [`Version`] which remembers the exact text it was parsed from.
+///
+/// Parsing with lenient [`ParseOptions`] normalizes the input, for example by
+/// stripping surrounding quotes. When rewriting manifests, this normalization
+/// can be undesirable. A [`PreservedVersion`] formats as the original text,
+/// while the parsed version is still available via
+/// [`PreservedVersion::version`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PreservedVersion<'a> {
+    version: Version,
+    original: &'a str,
+}
+
+impl<'a> PreservedVersion<'a> {
+    /// Parses `input` using the strict [default options][ParseOptions::default]
+    /// and keeps the original text.
+    pub fn parse(input: &'a str) -> Result<Self, VersionParseError> {
+        Self::parse_with_options(input, &ParseOptions::default())
+    }
+
+    /// Parses `input` like [`Version::from_str_with_options`] and keeps the
+    /// original text, before any normalization.
+    pub fn parse_with_options(
+        input: &'a str,
+        options: &ParseOptions,
+    ) -> Result<Self, VersionParseError> {
+        let version = Version::from_str_with_options(input, options)?;
+
+        Ok(Self {
+            version,
+            original: input,
+        })
+    }
+
+    /// Returns the parsed version.
+    pub fn version(&self) -> Version {
+        self.version
+    }
+
+    /// Returns the exact text this version was parsed from.
+    pub fn original(&self) -> &'a str {
+        self.original
+    }
+
+    /// Returns `true` if the original text had to be normalized, which means
+    /// it differs from the canonical form of the parsed version.
+    pub fn was_normalized(&self) -> bool {
+        self.original != self.version.to_owned_string()
+    }
+}
+
+impl From<PreservedVersion<'_>> for Version {
+    fn from(preserved: PreservedVersion<'_>) -> Self {
+        preserved.version
+    }
+}
+
+impl Display for PreservedVersion<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.original)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("v1", "v1")]
+    #[case("\"v1beta1\"", "v1beta1")]
+    #[case("'v2alpha1'\r\n", "v2alpha1")]
+    #[case("\u{feff}v1", "v1")]
+    fn parse_with_options(#[case] input: &str, #[case] canonical: &str) {
+        let preserved = PreservedVersion::parse_with_options(input, &ParseOptions::lenient())
+            .expect("must be a valid version");
+
+        assert_eq!(preserved.to_string(), input);
+        assert_eq!(preserved.original(), input);
+        assert_eq!(preserved.version().to_string(), canonical);
+        assert_eq!(preserved.was_normalized(), input != canonical);
+    }
+
+    #[rstest]
+    #[case("\"v1\"")]
+    #[case("v01")]
+    fn parse_strict_err(#[case] input: &str) {
+        assert_eq!(
+            PreservedVersion::parse(input),
+            Err(Version::from_str(input).unwrap_err())
+        );
+    }
+
+    #[test]
+    fn into_version() {
+        let preserved = PreservedVersion::parse("v1beta1").unwrap();
+        assert_eq!(Version::from(preserved), Version::beta(1, 1));
+    }
+}
@@ -0,0 +1,129 @@
+use alloc::string::{String, ToString};
+use core::{cmp::Ordering, fmt::Display, str::FromStr};
+
+use crate::Version;
+
+/// A version name which may or may not conform to the Kubernetes version
+/// format. The API server accepts arbitrary version names, like `foo1`, for
+/// aggregated APIs and orders them after all conforming versions.
+///
+/// For canonical versions, raw versions are ordered like
+/// `CompareKubeAwareVersionStrings` from apimachinery: conforming versions
+/// are compared by their Kubernetes priority (see [`Version::priority_cmp`])
+/// and are greater than all non-conforming names. Non-conforming names are
+/// ordered alphabetically, with `foo1` being greater than `foo10`, so that
+/// sorting in descending order lists them alphabetically.
+///
+/// Unlike upstream, which treats `v01` as the kube-like version `v1`, numbers
+/// with leading zeros are not conforming and are ordered as plain names.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RawVersion {
+    /// A version conforming to the Kubernetes version format, like `v1beta1`.
+    Conforming(Version),
+
+    /// A non-conforming version name, like `foo1`.
+    NonConforming(String),
+}
+
+impl RawVersion {
+    /// Parses `input` into a [`RawVersion`]. This never fails: input which
+    /// can't be parsed by [`Version::from_str`] is kept verbatim as a
+    /// non-conforming version name.
+    pub fn parse_lenient(input: &str) -> Self {
+        match Version::from_str(input) {
+            Ok(version) => Self::Conforming(version),
+            Err(_) => Self::NonConforming(input.to_string()),
+        }
+    }
+
+    /// Returns the conforming version, or [`None`] for non-conforming names.
+    pub fn as_version(&self) -> Option<&Version> {
+        match self {
+            Self::Conforming(version) => Some(version),
+            Self::NonConforming(_) => None,
+        }
+    }
+}
+
+impl From<Version> for RawVersion {
+    fn from(version: Version) -> Self {
+        Self::Conforming(version)
+    }
+}
+
+impl PartialOrd for RawVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RawVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Conforming(lhs), Self::Conforming(rhs)) => lhs.priority_cmp(rhs),
+            (Self::Conforming(_), Self::NonConforming(_)) => Ordering::Greater,
+            (Self::NonConforming(_), Self::Conforming(_)) => Ordering::Less,
+            (Self::NonConforming(lhs), Self::NonConforming(rhs)) => rhs.cmp(lhs),
+        }
+    }
+}
+
+impl Display for RawVersion {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Conforming(version) => write!(f, "{version}"),
+            Self::NonConforming(name) => f.write_str(name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        test_support::{kubernetes_docs_versions, KUBERNETES_DOCS_PRIORITY},
+        Level,
+    };
+
+    #[rstest]
+    #[case(
+        "v1beta1",
+        RawVersion::Conforming(Version::new(1, Some(Level::Beta(1))))
+    )]
+    #[case("foo1", RawVersion::NonConforming("foo1".to_string()))]
+    #[case("v1gamma1", RawVersion::NonConforming("v1gamma1".to_string()))]
+    fn parse_lenient(#[case] input: &str, #[case] expected: RawVersion) {
+        let version = RawVersion::parse_lenient(input);
+        assert_eq!(version, expected);
+        assert_eq!(version.to_string(), input);
+    }
+
+    #[test]
+    fn ord() {
+        let mut versions: Vec<_> = ["foo10", "foo1"]
+            .iter()
+            .map(|input| RawVersion::parse_lenient(input))
+            .chain(kubernetes_docs_versions().into_iter().map(RawVersion::from))
+            .collect();
+        versions.sort_by(|lhs, rhs| rhs.cmp(lhs));
+
+        // Non-conforming names sort after all conforming versions.
+        let expected: Vec<_> = KUBERNETES_DOCS_PRIORITY
+            .iter()
+            .chain(&["foo1", "foo10"])
+            .map(ToString::to_string)
+            .collect();
+
+        let versions: Vec<_> = versions.iter().map(ToString::to_string).collect();
+        assert_eq!(versions, expected);
+    }
+
+    #[test]
+    fn leading_zero_is_non_conforming() {
+        let version = RawVersion::parse_lenient("v01");
+        assert_eq!(version, RawVersion::NonConforming("v01".to_string()));
+        assert!(version < RawVersion::parse_lenient("v1alpha1"));
+    }
+}
@@ -0,0 +1,60 @@
+use core::cmp::Ordering;
+
+/// The stability of a Kubernetes resource [`Version`][crate::Version], see
+/// [`Version::stability`][crate::Version::stability].
+///
+/// Stabilities are ordered from least to most stable, so that
+/// `Stability::Stable > Stability::Beta > Stability::Alpha` holds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Stability {
+    /// Generally available versions without a level, like `v1`.
+    Stable,
+
+    /// Beta versions, like `v1beta1`.
+    Beta,
+
+    /// Alpha versions, like `v1alpha1`.
+    Alpha,
+}
+
+impl Stability {
+    /// Returns the same rank as [`Version::stability_rank`].
+    ///
+    /// [`Version::stability_rank`]: crate::Version::stability_rank
+    pub(crate) fn rank(&self) -> u8 {
+        match self {
+            Stability::Stable => 2,
+            Stability::Beta => 1,
+            Stability::Alpha => 0,
+        }
+    }
+}
+
+impl PartialOrd for Stability {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Stability {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ord() {
+        let mut stabilities = vec![Stability::Beta, Stability::Stable, Stability::Alpha];
+        stabilities.sort();
+
+        assert_eq!(
+            stabilities,
+            [Stability::Alpha, Stability::Beta, Stability::Stable]
+        );
+        assert!(Stability::Stable > Stability::Beta);
+    }
+}
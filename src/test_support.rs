@@ -0,0 +1,57 @@
+//! Helpers shared by the unit tests and the benchmarks. The benchmarks include
+//! this file using `#[path]`, so it may only use the public API, imported
+//! from the parent module.
+
+use super::{Level, Version};
+
+/// The example order from the Kubernetes documentation on version priority,
+/// in descending priority and without the non-conforming names.
+///
+/// See <https://kubernetes.io/docs/tasks/extend-kubernetes/custom-resources/custom-resource-definition-versioning/#version-priority>
+pub(crate) const KUBERNETES_DOCS_PRIORITY: [&str; 8] = [
+    "v10",
+    "v2",
+    "v1",
+    "v11beta2",
+    "v10beta3",
+    "v3beta1",
+    "v12alpha1",
+    "v11alpha2",
+];
+
+/// Parses [`KUBERNETES_DOCS_PRIORITY`] in ascending priority, which is the
+/// reverse of the expected order after sorting by descending priority.
+pub(crate) fn kubernetes_docs_versions() -> Vec<Version> {
+    use core::str::FromStr;
+
+    KUBERNETES_DOCS_PRIORITY
+        .iter()
+        .rev()
+        .map(|input| Version::from_str(input).unwrap())
+        .collect()
+}
+
+/// Generates pseudo-random versions using a linear congruential generator, so
+/// that every run sorts the same input.
+pub(crate) fn random_versions(count: usize) -> Vec<Version> {
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = move || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        state >> 33
+    };
+
+    (0..count)
+        .map(|_| {
+            let major = next() % 20;
+            let level = match next() % 3 {
+                0 => None,
+                1 => Some(Level::Beta(next() % 10)),
+                _ => Some(Level::Alpha(next() % 10)),
+            };
+
+            Version::new(major, level)
+        })
+        .collect()
+}
@@ -1,26 +1,223 @@
-use std::{cmp::Ordering, fmt::Display, num::ParseIntError, str::FromStr};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{cmp::Ordering, fmt::Display, num::ParseIntError, str::FromStr};
 
-use lazy_static::lazy_static;
-use regex::Regex;
-use snafu::{OptionExt, ResultExt, Snafu};
+use snafu::{ensure, OptionExt, ResultExt, Snafu};
 
-use crate::{Level, ParseLevelError};
-
-lazy_static! {
-    static ref VERSION_REGEX: Regex =
-        Regex::new(r"^v(?P<major>\d+)(?P<level>[a-z0-9][a-z0-9-]{0,60}[a-z0-9])?$").unwrap();
-}
+use crate::{Level, ParseLevelError, ParseOptions, Stability};
 
 #[derive(Debug, PartialEq, Snafu)]
 pub enum VersionParseError {
-    #[snafu(display("invalid version format. Input is empty, contains non-ASCII characters or contains more than 63 characters"))]
+    #[snafu(display(
+        "invalid version format. Expected v<MAJOR>, v<MAJOR>beta<LEVEL> or v<MAJOR>alpha<LEVEL> with at most 63 characters"
+    ))]
     InvalidFormat,
 
+    #[snafu(display("input contains non-ASCII characters"))]
+    NonAscii,
+
     #[snafu(display("failed to parse major version"))]
     ParseMajorVersion { source: ParseIntError },
 
     #[snafu(display("failed to parse version level"))]
     ParseLevel { source: ParseLevelError },
+
+    #[snafu(display("unexpected character {character:?} at index {index}"))]
+    TrailingCharacters { character: char, index: usize },
+
+    #[snafu(display("invalid annotation, expected a parenthesized comment after the version"))]
+    InvalidAnnotation,
+
+    #[snafu(display(
+        "input looks like a Kubernetes cluster version, expected an API version like v1 or v1beta1"
+    ))]
+    LooksLikeClusterVersion,
+}
+
+#[derive(Debug, PartialEq, Snafu)]
+pub enum VersionListParseError {
+    #[snafu(display("empty version at position {position}, like from a trailing comma"))]
+    EmptyVersion { position: usize },
+
+    #[snafu(display("failed to parse version {input:?} at position {position}"))]
+    ParseVersion {
+        source: VersionParseError,
+        input: String,
+        position: usize,
+    },
+}
+
+impl From<ParseLevelError> for VersionParseError {
+    fn from(source: ParseLevelError) -> Self {
+        Self::ParseLevel { source }
+    }
+}
+
+impl VersionParseError {
+    /// Renders `input` on one line and a caret (`^`) pointing at the offending
+    /// character on the line beneath, followed by the error message.
+    ///
+    /// `input` should be the input which produced this error. For errors
+    /// returned by [`Version::from_str_with_options`], indices refer to the
+    /// normalized input, for example without surrounding quotes, so the
+    /// normalized input should be passed. Other input never panics, but the
+    /// caret may be misplaced or clamped to the end of `input`.
+    ///
+    /// ```text
+    /// v1beta1x
+    ///        ^ unexpected character 'x' at index 7
+    /// ```
+    pub fn render_with_source(&self, input: &str) -> String {
+        let offset = self.offset(input).min(input.len());
+        let offset = (0..=offset)
+            .rev()
+            .find(|&offset| input.is_char_boundary(offset))
+            .unwrap_or_default();
+
+        let padding = input[..offset].chars().count();
+        format!("{input}\n{:padding$}^ {self}", "")
+    }
+
+    /// Returns the byte offset of the offending character in `input`.
+    fn offset(&self, input: &str) -> usize {
+        if matches!(self, Self::NonAscii) {
+            return input.bytes().take_while(u8::is_ascii).count();
+        }
+
+        if let Self::TrailingCharacters { index, .. } = self {
+            return *index;
+        }
+
+        let Some(parts) = Parts::scan(input) else {
+            return 0;
+        };
+
+        if parts.major.is_empty()
+            || has_leading_zero(parts.major)
+            || matches!(self, Self::ParseMajorVersion { .. })
+        {
+            return 1;
+        }
+
+        if !parts.has_known_identifier() {
+            return parts.identifier_start();
+        }
+
+        if has_leading_zero(parts.level) {
+            return parts.level_start();
+        }
+
+        match self {
+            Self::ParseLevel {
+                source: ParseLevelError::ParseVersion { .. },
+            } => parts.level_start(),
+            _ => parts.rest_start(),
+        }
+    }
+}
+
+/// Returns `true` if the decimal number `digits` has a leading zero, like
+/// `01`. A single `0` has no leading zero.
+pub(crate) fn has_leading_zero(digits: &str) -> bool {
+    digits.len() > 1 && digits.starts_with('0')
+}
+
+/// Splits `input` at the first byte not matching `pred`. The predicate must
+/// only match ASCII bytes, so that the split is on a character boundary.
+pub(crate) fn split_while(input: &str, pred: fn(&u8) -> bool) -> (&str, &str) {
+    let index = input.bytes().take_while(pred).count();
+    input.split_at(index)
+}
+
+/// The structural parts of a version string, like `1`, `beta` and `2` for
+/// `v1beta2`. Any part may be empty, the parts are only split and not
+/// validated. Everything following the level is collected in `rest`.
+struct Parts<'a> {
+    major: &'a str,
+    identifier: &'a str,
+    level: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Parts<'a> {
+    /// Splits `input` into its parts. Returns [`None`] if `input` doesn't
+    /// start with `v`.
+    fn scan(input: &'a str) -> Option<Self> {
+        let input = input.strip_prefix('v')?;
+        let (major, input) = split_while(input, u8::is_ascii_digit);
+        let (identifier, input) = split_while(input, u8::is_ascii_lowercase);
+        let (level, rest) = split_while(input, u8::is_ascii_digit);
+
+        Some(Self {
+            major,
+            identifier,
+            level,
+            rest,
+        })
+    }
+
+    fn identifier_start(&self) -> usize {
+        1 + self.major.len()
+    }
+
+    fn level_start(&self) -> usize {
+        self.identifier_start() + self.identifier.len()
+    }
+
+    fn rest_start(&self) -> usize {
+        self.level_start() + self.level.len()
+    }
+
+    fn has_known_identifier(&self) -> bool {
+        matches!(self.identifier, "alpha" | "beta")
+    }
+
+    /// Returns `true` if the parts start like a cluster version, which is
+    /// `v<MAJOR>.<MINOR>`, like `v1.25`.
+    fn looks_like_cluster_version(&self) -> bool {
+        !self.major.is_empty()
+            && self.identifier.is_empty()
+            && self
+                .rest
+                .strip_prefix('.')
+                .is_some_and(|minor| minor.starts_with(|c: char| c.is_ascii_digit()))
+    }
+
+    /// Returns the first trailing character following a complete version and
+    /// its byte index, like `('x', 7)` for `v1beta1x` or `('-', 2)` for
+    /// `v1-2`. Returns [`None`] if there are no trailing characters or if the
+    /// parts don't start with a complete version, like `v1gamma1`.
+    fn trailing_characters(&self) -> Option<(char, usize)> {
+        if self.major.is_empty() {
+            return None;
+        }
+
+        if self.identifier.is_empty() {
+            let character = self.rest.chars().next()?;
+            return Some((character, self.identifier_start()));
+        }
+
+        if !self.has_known_identifier() || self.level.is_empty() {
+            return None;
+        }
+
+        let character = self.rest.chars().next()?;
+        Some((character, self.rest_start()))
+    }
+
+    /// Returns `true` if the parts match the `v<MAJOR>((alpha|beta)<LEVEL>)`
+    /// grammar.
+    fn is_complete(&self) -> bool {
+        let level_valid = match self.identifier {
+            "" => self.level.is_empty(),
+            _ => self.has_known_identifier() && !self.level.is_empty(),
+        };
+
+        !self.major.is_empty() && level_valid && self.rest.is_empty()
+    }
 }
 
 /// A Kubernetes resource version with the `v<MAJOR>(beta/alpha<LEVEL>)`
@@ -28,41 +225,62 @@ pub enum VersionParseError {
 ///
 /// The version must follow the DNS label format defined [here][1].
 ///
+/// Versions only consist of integers and are therefore [`Copy`].
+///
 /// ### See
 ///
 /// - <https://github.com/kubernetes/community/blob/master/contributors/devel/sig-architecture/api-conventions.md#api-conventions>
 /// - <https://kubernetes.io/docs/reference/using-api/#api-versioning>
 ///
 /// [1]: https://github.com/kubernetes/design-proposals-archive/blob/main/architecture/identifiers.md#definitions
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct Version {
     pub major: u64,
     pub level: Option<Level>,
 }
 
+/// A flattened view of a [`Version`], which allows matching on the stability,
+/// major and level number at once. See [`Version::classify`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VersionClass {
+    Ga { major: u64 },
+    Beta { major: u64, n: u64 },
+    Alpha { major: u64, n: u64 },
+}
+
 impl FromStr for Version {
     type Err = VersionParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let captures = VERSION_REGEX.captures(input).context(InvalidFormatSnafu)?;
+        ensure!(input.is_ascii(), NonAsciiSnafu);
+
+        // Versions must be valid DNS labels, which are at most 63 characters
+        // long. This is checked before scanning, so that overly long input is
+        // rejected without looking at its contents.
+        ensure!(input.len() <= 63, InvalidFormatSnafu);
+        let parts = Parts::scan(input).context(InvalidFormatSnafu)?;
 
-        let major = captures
-            .name("major")
-            .expect("internal error: check that the correct match label is specified")
-            .as_str()
-            .parse::<u64>()
-            .context(ParseMajorVersionSnafu)?;
+        ensure!(
+            !parts.looks_like_cluster_version(),
+            LooksLikeClusterVersionSnafu
+        );
 
-        let level = captures
-            .name("level")
-            .expect("internal error: check that the correct match label is specified")
-            .as_str();
+        if let Some((character, index)) = parts.trailing_characters() {
+            return TrailingCharactersSnafu { character, index }.fail();
+        }
+
+        ensure!(parts.is_complete(), InvalidFormatSnafu);
 
-        if level.is_empty() {
+        // Leading zeros are rejected, so that `v01` can't be parsed into a
+        // version equal to `v1`.
+        ensure!(!has_leading_zero(parts.major), InvalidFormatSnafu);
+        let major = parts.major.parse::<u64>().context(ParseMajorVersionSnafu)?;
+
+        if parts.identifier.is_empty() {
             return Ok(Self { major, level: None });
         }
 
-        let level = Level::from_str(level).context(ParseLevelSnafu)?;
+        let level = Level::from_str(&input[parts.identifier_start()..]).context(ParseLevelSnafu)?;
 
         Ok(Self {
             level: Some(level),
@@ -73,22 +291,47 @@ impl FromStr for Version {
 
 impl PartialOrd for Version {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        match self.major.partial_cmp(&other.major) {
-            Some(core::cmp::Ordering::Equal) => {}
-            ord => return ord,
-        }
+        Some(self.cmp(other))
+    }
+}
 
-        match (&self.level, &other.level) {
-            (Some(lhs), Some(rhs)) => lhs.partial_cmp(rhs),
-            (Some(_), None) => Some(Ordering::Less),
-            (None, Some(_)) => Some(Ordering::Greater),
-            (None, None) => Some(Ordering::Equal),
-        }
+/// Versions are ordered by their Kubernetes priority, see
+/// [`Version::priority_cmp`]. Sorting a list of versions in descending order
+/// thus puts the preferred version first.
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority_cmp(other)
+    }
+}
+
+/// Compares only the stability of the version with `other`, ignoring the
+/// major and level numbers. `v1alpha1 == Stability::Alpha` and
+/// `v2alpha3 == Stability::Alpha` both hold.
+impl PartialEq<Stability> for Version {
+    fn eq(&self, other: &Stability) -> bool {
+        self.stability_rank() == other.rank()
+    }
+}
+
+/// Compares only the stability of the version with `other`, ignoring the
+/// major and level numbers. This allows filtering like
+/// `version >= Stability::Beta` to mean "at least beta stability".
+impl PartialOrd<Stability> for Version {
+    fn partial_cmp(&self, other: &Stability) -> Option<Ordering> {
+        self.stability_rank().partial_cmp(&other.rank())
+    }
+}
+
+/// Defaults to `v1`, the first stable version, which is the version most
+/// resources start out with.
+impl Default for Version {
+    fn default() -> Self {
+        Self::ga(1)
     }
 }
 
 impl Display for Version {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match &self.level {
             Some(minor) => write!(f, "v{}{}", self.major, minor),
             None => write!(f, "v{}", self.major),
@@ -96,28 +339,1964 @@ impl Display for Version {
     }
 }
 
+/// Converts into the canonical string form, like [`Display`]. This uses
+/// [`Version::to_owned_string`], which allocates exactly once.
+impl From<Version> for String {
+    fn from(version: Version) -> Self {
+        version.to_owned_string()
+    }
+}
+
+impl TryFrom<String> for Version {
+    type Error = VersionParseError;
+
+    fn try_from(input: String) -> Result<Self, Self::Error> {
+        Self::from_str(&input)
+    }
+}
+
+/// Parses a version from raw bytes, like a protobuf field. Input which is not
+/// ASCII, including invalid UTF-8, returns [`VersionParseError::NonAscii`].
+impl TryFrom<&[u8]> for Version {
+    type Error = VersionParseError;
+
+    fn try_from(input: &[u8]) -> Result<Self, Self::Error> {
+        ensure!(input.is_ascii(), NonAsciiSnafu);
+        let input = core::str::from_utf8(input).expect("ASCII is always valid UTF-8");
+        Self::from_str(input)
+    }
+}
+
 impl Version {
-    pub fn new(major: u64, minor: Option<Level>) -> Self {
+    /// Parses a [`Version`] like [`Version::from_str`], but allows customizing
+    /// the parsing behaviour using [`ParseOptions`]. Options which only apply
+    /// to groups are ignored.
+    pub fn from_str_with_options(
+        input: &str,
+        options: &ParseOptions,
+    ) -> Result<Self, VersionParseError> {
+        Self::from_str(&options.normalize(input))
+    }
+
+    /// Parses a [`Version`] after converting ASCII upper case letters to
+    /// lower case, like `V1Beta1` to `v1beta1`. The returned version is always
+    /// canonical, while [`Version::from_str`] keeps rejecting upper case.
+    pub fn from_str_normalized(input: &str) -> Result<Self, VersionParseError> {
+        Self::from_str_with_options(input, &ParseOptions::lowercase())
+    }
+
+    /// Parses a comma-separated list of versions, like `v1,v1beta1,v2alpha1`,
+    /// keeping their order. Whitespace around each version is ignored and
+    /// empty input results in an empty list. Empty elements, like from a
+    /// trailing comma in `v1,`, are rejected. The returned error contains the
+    /// zero-based position of the first version which failed to parse.
+    pub fn parse_list(input: &str) -> Result<Vec<Self>, VersionListParseError> {
+        if input.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+
+        input
+            .split(',')
+            .map(str::trim)
+            .enumerate()
+            .map(|(position, input)| {
+                ensure!(!input.is_empty(), EmptyVersionSnafu { position });
+                Self::from_str(input).context(ParseVersionSnafu { input, position })
+            })
+            .collect()
+    }
+
+    /// Parses a comma-separated list of versions like [`Version::parse_list`],
+    /// removes duplicates and returns the versions sorted by descending
+    /// Kubernetes priority (see [`Version::priority_cmp`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`VersionListParseError`] of [`Version::parse_list`] for
+    /// the first invalid or empty element. This used to be a plain
+    /// [`VersionParseError`], which couldn't tell which element failed; the
+    /// list error wraps it together with the position of the element.
+    pub fn parse_many_dedup_sorted(input: &str) -> Result<Vec<Self>, VersionListParseError> {
+        let mut versions = Self::parse_list(input)?;
+
+        versions.sort_by(|lhs, rhs| rhs.priority_cmp(lhs));
+        versions.dedup();
+
+        Ok(versions)
+    }
+
+    /// Parses a [`Version`] from trusted input, skipping the format validation
+    /// done by [`Version::from_str`].
+    ///
+    /// The caller must guarantee that the input only contains ASCII characters
+    /// and is at most 63 characters long. This contract is only checked in
+    /// debug builds. Only structural errors, like a missing `v` prefix or an
+    /// invalid level, are returned.
+    pub fn from_ascii_unchecked(input: &str) -> Result<Self, VersionParseError> {
+        debug_assert!(
+            input.is_ascii() && input.len() <= 63,
+            "input must be ASCII and at most 63 characters long"
+        );
+
+        let parts = Parts::scan(input).context(InvalidFormatSnafu)?;
+        if let Some((character, index)) = parts.trailing_characters() {
+            return TrailingCharactersSnafu { character, index }.fail();
+        }
+
+        ensure!(
+            !parts.major.is_empty() && !has_leading_zero(parts.major),
+            InvalidFormatSnafu
+        );
+        let major = parts.major.parse::<u64>().context(ParseMajorVersionSnafu)?;
+
+        if parts.identifier.is_empty() {
+            return Ok(Self { major, level: None });
+        }
+
+        let level = Level::from_str(&input[parts.identifier_start()..]).context(ParseLevelSnafu)?;
+
+        Ok(Self {
+            level: Some(level),
+            major,
+        })
+    }
+
+    /// Parses a version followed by an optional parenthesized annotation, like
+    /// `v1beta1 (deprecated)`. The annotation is returned without the
+    /// parentheses and surrounding whitespace. Input without an annotation,
+    /// like `v1`, returns [`None`] as the annotation.
+    ///
+    /// Use [`Version::from_str`] to reject any trailing input.
+    pub fn from_str_with_annotation(
+        input: &str,
+    ) -> Result<(Self, Option<String>), VersionParseError> {
+        let (version, annotation) = match input.split_once(char::is_whitespace) {
+            Some((version, rest)) => {
+                let annotation = rest
+                    .trim()
+                    .strip_prefix('(')
+                    .and_then(|rest| rest.strip_suffix(')'))
+                    .context(InvalidAnnotationSnafu)?;
+
+                (version, Some(annotation.trim().to_string()))
+            }
+            None => (input, None),
+        };
+
+        Ok((Self::from_str(version)?, annotation))
+    }
+
+    /// Parses a version in a `const` context. The accepted format is the same
+    /// as the one of [`Version::from_str`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` is not a valid version. When used to initialize a
+    /// `const` or `static`, this turns an invalid literal into a compile
+    /// error instead of a runtime panic:
+    ///
+    /// ```
+    /// use k8s_version::{Level, Version};
+    ///
+    /// const V1BETA1: Version = Version::from_static("v1beta1");
+    /// assert_eq!(V1BETA1, Version::new(1, Some(Level::Beta(1))));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use k8s_version::Version;
+    ///
+    /// const V1GAMMA1: Version = Version::from_static("v1gamma1");
+    /// ```
+    ///
+    /// Outside of `const` contexts, the check happens at runtime like any
+    /// other panic, and the panic message can't name the reason why the
+    /// input is invalid. Prefer [`Version::from_str`] for non-literal input.
+    pub const fn from_static(input: &str) -> Self {
+        match Self::parse_const(input) {
+            Some(version) => version,
+            None => panic!("invalid Kubernetes version literal"),
+        }
+    }
+
+    /// Returns `true` if `input` is a valid version. This can be evaluated in
+    /// `const` contexts, for example in `const` assertions.
+    pub const fn is_valid(input: &str) -> bool {
+        Self::parse_const(input).is_some()
+    }
+
+    /// A `const` parser for the version format, returning [`None`] for any
+    /// invalid input.
+    const fn parse_const(input: &str) -> Option<Self> {
+        const fn parse_number(bytes: &[u8], mut index: usize) -> Option<(u64, usize)> {
+            let start = index;
+            let mut number: u64 = 0;
+
+            while index < bytes.len() && bytes[index].is_ascii_digit() {
+                let digit = (bytes[index] - b'0') as u64;
+                number = match number.checked_mul(10) {
+                    Some(number) => match number.checked_add(digit) {
+                        Some(number) => number,
+                        None => return None,
+                    },
+                    None => return None,
+                };
+                index += 1;
+            }
+
+            if index == start || (index - start > 1 && bytes[start] == b'0') {
+                return None;
+            }
+
+            Some((number, index))
+        }
+
+        const fn has_identifier(bytes: &[u8], index: usize, identifier: &[u8]) -> bool {
+            if bytes.len() - index < identifier.len() {
+                return false;
+            }
+
+            let mut offset = 0;
+            while offset < identifier.len() {
+                if bytes[index + offset] != identifier[offset] {
+                    return false;
+                }
+                offset += 1;
+            }
+
+            true
+        }
+
+        let bytes = input.as_bytes();
+        if bytes.is_empty() || bytes.len() > 63 || bytes[0] != b'v' {
+            return None;
+        }
+
+        let (major, index) = match parse_number(bytes, 1) {
+            Some(result) => result,
+            None => return None,
+        };
+
+        if index == bytes.len() {
+            return Some(Self { major, level: None });
+        }
+
+        let (is_beta, index) = if has_identifier(bytes, index, b"beta") {
+            (true, index + 4)
+        } else if has_identifier(bytes, index, b"alpha") {
+            (false, index + 5)
+        } else {
+            return None;
+        };
+
+        let (level, index) = match parse_number(bytes, index) {
+            Some(result) => result,
+            None => return None,
+        };
+
+        if index != bytes.len() {
+            return None;
+        }
+
+        let level = if is_beta {
+            Level::Beta(level)
+        } else {
+            Level::Alpha(level)
+        };
+
+        Some(Self {
+            major,
+            level: Some(level),
+        })
+    }
+
+    pub const fn new(major: u64, minor: Option<Level>) -> Self {
         Self {
             major,
             level: minor,
         }
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use rstest::rstest;
+    /// Creates a GA version, like `v2` for `Version::ga(2)`.
+    pub const fn ga(major: u64) -> Self {
+        Self::new(major, None)
+    }
 
-    #[rstest]
-    #[case("v1alpha12")]
-    #[case("v1alpha1")]
-    #[case("v1beta1")]
-    #[case("v1")]
-    fn valid_version(#[case] input: &str) {
-        let version = Version::from_str(input).unwrap();
-        assert_eq!(version.to_string(), input);
+    /// Creates a beta version, like `v2beta1` for `Version::beta(2, 1)`.
+    pub const fn beta(major: u64, n: u64) -> Self {
+        Self::new(major, Some(Level::Beta(n)))
+    }
+
+    /// Creates an alpha version, like `v2alpha1` for `Version::alpha(2, 1)`.
+    pub const fn alpha(major: u64, n: u64) -> Self {
+        Self::new(major, Some(Level::Alpha(n)))
+    }
+
+    /// Returns the major version, like `2` for `v2beta1`.
+    pub const fn major(&self) -> u64 {
+        self.major
+    }
+
+    /// Returns the level, like `beta1` for `v2beta1`, or [`None`] for stable
+    /// versions.
+    pub const fn level(&self) -> Option<Level> {
+        self.level
+    }
+
+    /// Returns the version without the leading `v`, for example `1beta1`
+    /// instead of `v1beta1`. This form is meant for display purposes only and
+    /// is **not** accepted by [`Version::from_str`].
+    pub fn display_compact(&self) -> String {
+        match &self.level {
+            Some(level) => format!("{}{}", self.major, level),
+            None => self.major.to_string(),
+        }
+    }
+
+    /// Appends the canonical form of this version, like `v1beta1`, to `buf`.
+    /// This allows reusing a single buffer instead of allocating a new
+    /// [`String`] for every version.
+    pub fn format_into(&self, buf: &mut String) {
+        use core::fmt::Write;
+
+        write!(buf, "{self}").expect("writing into a String never fails");
+    }
+
+    /// Returns `true` if this is a stable version without a level, like `v1`.
+    /// [`Version::is_ga`] is an alias using the "generally available"
+    /// vocabulary of the Kubernetes documentation.
+    pub const fn is_stable(&self) -> bool {
+        self.level.is_none()
+    }
+
+    /// Returns `true` if this is a generally available (GA) version, like
+    /// `v1`. This is an alias for [`Version::is_stable`].
+    pub const fn is_ga(&self) -> bool {
+        self.is_stable()
+    }
+
+    /// Returns `true` if this is a beta version, like `v1beta1`.
+    pub const fn is_beta(&self) -> bool {
+        matches!(self.level, Some(Level::Beta(_)))
+    }
+
+    /// Returns `true` if this is an alpha version, like `v1alpha1`.
+    pub const fn is_alpha(&self) -> bool {
+        matches!(self.level, Some(Level::Alpha(_)))
+    }
+
+    /// Returns `true` if this is a pre-release version, which is either a beta
+    /// or an alpha version. This is the opposite of [`Version::is_stable`].
+    pub const fn is_prerelease(&self) -> bool {
+        self.level.is_some()
+    }
+
+    /// Returns the canonical form of this version, like [`ToString::to_string`],
+    /// but builds the string directly instead of going through the
+    /// [`Display`] machinery. The returned [`String`] is allocated exactly
+    /// once with the required capacity.
+    pub fn to_owned_string(&self) -> String {
+        fn decimal_len(n: u64) -> usize {
+            n.checked_ilog10().map_or(1, |log| log as usize + 1)
+        }
+
+        fn push_decimal(buf: &mut String, mut n: u64) {
+            let mut digits = [0u8; 20];
+            let mut start = digits.len();
+
+            loop {
+                start -= 1;
+                digits[start] = b'0' + (n % 10) as u8;
+                n /= 10;
+
+                if n == 0 {
+                    break;
+                }
+            }
+
+            buf.push_str(core::str::from_utf8(&digits[start..]).expect("digits are valid UTF-8"));
+        }
+
+        let level = match self.level {
+            Some(Level::Beta(n)) => Some(("beta", n)),
+            Some(Level::Alpha(n)) => Some(("alpha", n)),
+            None => None,
+        };
+
+        let len = 1
+            + decimal_len(self.major)
+            + level.map_or(0, |(identifier, n)| identifier.len() + decimal_len(n));
+
+        let mut buf = String::with_capacity(len);
+        buf.push('v');
+        push_decimal(&mut buf, self.major);
+
+        if let Some((identifier, n)) = level {
+            buf.push_str(identifier);
+            push_decimal(&mut buf, n);
+        }
+
+        buf
+    }
+
+    /// Returns the rank the ordering assigns to the stability of this version.
+    /// A higher rank means a more stable version:
+    ///
+    /// - `2`: Stable (GA), like `v1`
+    /// - `1`: Beta, like `v1beta1`
+    /// - `0`: Alpha, like `v1alpha1`
+    pub fn stability_rank(&self) -> u8 {
+        self.stability().rank()
+    }
+
+    /// Returns the stability of this version, like [`Stability::Beta`] for
+    /// `v2beta3`.
+    pub const fn stability(&self) -> Stability {
+        match self.level {
+            None => Stability::Stable,
+            Some(Level::Beta(_)) => Stability::Beta,
+            Some(Level::Alpha(_)) => Stability::Alpha,
+        }
+    }
+
+    /// Returns the signed number of steps from `self` to `other`, if both
+    /// versions are on the same track. A track is made up of all versions
+    /// sharing the same major version and stability, like `v1beta1`, `v1beta2`
+    /// and so on. A step is a single level increment within that track, which
+    /// means `v1beta1.distance(&v1beta4)` is `Some(3)` and
+    /// `v1beta4.distance(&v1beta1)` is `Some(-3)`.
+    ///
+    /// Returns [`None`] if the versions are on different tracks. Because stable
+    /// versions have no level, two stable versions are only on the same track
+    /// if they are equal, in which case the distance is `Some(0)`. [`None`] is
+    /// also returned if the distance doesn't fit into an [`i64`].
+    pub fn distance(&self, other: &Version) -> Option<i64> {
+        if self.major != other.major {
+            return None;
+        }
+
+        match (&self.level, &other.level) {
+            (None, None) => Some(0),
+            (Some(Level::Beta(lhs)), Some(Level::Beta(rhs)))
+            | (Some(Level::Alpha(lhs)), Some(Level::Alpha(rhs))) => {
+                i64::try_from(*rhs as i128 - *lhs as i128).ok()
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the next major version with the level cleared, like `v2` for
+    /// `v1beta1`. The major version saturates at [`u64::MAX`] instead of
+    /// overflowing.
+    pub fn saturating_bump_major(&self) -> Version {
+        Version::new(self.major.saturating_add(1), None)
+    }
+
+    /// Returns the major version and level as a tuple, like `(2, Some(beta1))`
+    /// for `v2beta1`. This is useful for matching on both at once.
+    pub const fn to_tuple(&self) -> (u64, Option<Level>) {
+        (self.major, self.level)
+    }
+
+    /// Returns the GA version with the same major version, like `v2` for
+    /// `v2beta3`. GA versions are returned unchanged.
+    pub const fn to_ga(&self) -> Version {
+        Version::new(self.major, None)
+    }
+
+    /// Clears the level of this version in place, turning it into the GA
+    /// version with the same major version. See [`Version::to_ga`].
+    pub fn promote_to_ga(&mut self) {
+        self.level = None;
+    }
+
+    /// Returns an iterator over the GA versions with the given `majors`, like
+    /// `v1`, `v2` and `v3` for `1..=3`.
+    ///
+    /// This is a substitute for range syntax like `v1..=v3`, because
+    /// [`Step`][core::iter::Step] is not stable yet. Ranges are restricted to
+    /// GA versions, because ranges across stabilities are ambiguous.
+    pub fn ga_range<I>(majors: I) -> impl Iterator<Item = Version>
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        majors.into_iter().map(|major| Version::new(major, None))
+    }
+
+    /// Moves this version one step up the stability ladder of its major
+    /// version: alpha versions are promoted to the first beta version and beta
+    /// versions to the GA version, like `v1alpha2` to `v1beta1` and `v1beta1`
+    /// to `v1`. GA versions are at the top of the ladder and are returned
+    /// unchanged.
+    pub const fn promote(&self) -> Version {
+        match self.level {
+            Some(Level::Alpha(_)) => Version::new(self.major, Some(Level::Beta(1))),
+            Some(Level::Beta(_)) | None => self.to_ga(),
+        }
+    }
+
+    /// Moves this version one step down the stability ladder of its major
+    /// version: GA versions are demoted to the first beta version and beta
+    /// versions to the first alpha version, like `v1` to `v1beta1` and
+    /// `v1beta2` to `v1alpha1`. Alpha versions are at the bottom of the ladder
+    /// and are returned unchanged.
+    pub const fn demote(&self) -> Version {
+        match self.level {
+            None => Version::new(self.major, Some(Level::Beta(1))),
+            Some(Level::Beta(_)) => Version::new(self.major, Some(Level::Alpha(1))),
+            Some(Level::Alpha(_)) => *self,
+        }
+    }
+
+    /// Classifies this version, which enables exhaustive matching without
+    /// dealing with the optional [`Level`].
+    pub fn classify(&self) -> VersionClass {
+        let major = self.major;
+
+        match self.level {
+            None => VersionClass::Ga { major },
+            Some(Level::Beta(n)) => VersionClass::Beta { major, n },
+            Some(Level::Alpha(n)) => VersionClass::Alpha { major, n },
+        }
+    }
+
+    /// Compares two versions by their Kubernetes priority, which is the order
+    /// used by apimachinery to pick preferred versions. This is the ordering
+    /// of the [`Ord`] implementation. Stable versions have the highest
+    /// priority, followed by beta and then alpha versions. Within the same
+    /// stability, a higher major and then a higher level number has the
+    /// higher priority, for example:
+    ///
+    /// `v10 > v2 > v1 > v11beta2 > v10beta3 > v3beta1 > v12alpha1 > v11alpha2`
+    ///
+    /// See <https://kubernetes.io/docs/tasks/extend-kubernetes/custom-resources/custom-resource-definition-versioning/#version-priority>
+    pub fn priority_cmp(&self, other: &Self) -> Ordering {
+        self.priority_key().cmp(&other.priority_key())
+    }
+
+    /// Returns a sort key whose natural ordering matches the Kubernetes
+    /// priority (see [`Version::priority_cmp`]). The key consists of:
+    ///
+    /// 1. the stability rank (see [`Version::stability_rank`]),
+    /// 2. the major version,
+    /// 3. the level number, which is `0` for stable versions.
+    ///
+    /// This enables sorting with [`slice::sort_by_key`], for example by a
+    /// secondary field first.
+    pub fn priority_key(&self) -> (u8, u64, u64) {
+        let level = self.level.as_ref().map_or(0, Level::number);
+        (self.stability_rank(), self.major, level)
+    }
+
+    /// Returns `true` if this version has a higher Kubernetes priority than
+    /// `other` (see [`Version::priority_cmp`]), like `v1` compared to
+    /// `v1beta1`.
+    pub fn is_newer_than(&self, other: &Self) -> bool {
+        self.priority_cmp(other) == Ordering::Greater
+    }
+
+    /// Returns `true` if this version has a lower Kubernetes priority than
+    /// `other` (see [`Version::priority_cmp`]), like `v2alpha1` compared to
+    /// `v1beta1`.
+    pub fn is_older_than(&self, other: &Self) -> bool {
+        self.priority_cmp(other) == Ordering::Less
+    }
+
+    /// The largest major version supported by [`Version::to_packed`].
+    pub const MAX_PACKED_MAJOR: u64 = (1 << 30) - 1;
+
+    /// The largest level number supported by [`Version::to_packed`].
+    pub const MAX_PACKED_LEVEL: u64 = (1 << 32) - 1;
+
+    /// Packs this version into a single [`u64`], whose numeric order matches
+    /// the Kubernetes priority (see [`Version::priority_cmp`]). This allows
+    /// sorting by a plain integer key and storing versions compactly. The bit
+    /// layout, from the most significant bit, is:
+    ///
+    /// - bits 62-63: the stability rank (see [`Version::stability_rank`]),
+    /// - bits 32-61: the major version, up to [`Version::MAX_PACKED_MAJOR`],
+    /// - bits 0-31: the level number, up to [`Version::MAX_PACKED_LEVEL`],
+    ///   which is `0` for stable versions.
+    ///
+    /// Returns [`None`] if the major version or the level number exceed the
+    /// supported maximum. Use [`Version::from_packed`] to unpack the version.
+    pub fn to_packed(&self) -> Option<u64> {
+        let (rank, major, level) = self.priority_key();
+
+        if major > Self::MAX_PACKED_MAJOR || level > Self::MAX_PACKED_LEVEL {
+            return None;
+        }
+
+        Some((rank as u64) << 62 | major << 32 | level)
+    }
+
+    /// Unpacks a version packed by [`Version::to_packed`]. Returns [`None`]
+    /// if `packed` doesn't follow the documented bit layout, like a stable
+    /// version with a non-zero level number.
+    pub fn from_packed(packed: u64) -> Option<Self> {
+        let major = (packed >> 32) & Self::MAX_PACKED_MAJOR;
+        let level = packed & Self::MAX_PACKED_LEVEL;
+
+        match packed >> 62 {
+            2 if level == 0 => Some(Self::ga(major)),
+            1 => Some(Self::beta(major, level)),
+            0 => Some(Self::alpha(major, level)),
+            _ => None,
+        }
+    }
+
+    /// Returns the preferred version among `versions`, which is the version
+    /// with the highest Kubernetes priority. This is how the API server picks
+    /// the preferred version of a group. Returns [`None`] if there are no
+    /// versions.
+    pub fn preferred<I>(versions: I) -> Option<Version>
+    where
+        I: IntoIterator<Item = Version>,
+    {
+        versions.into_iter().max()
+    }
+
+    /// Returns all versions in `known` which this version supersedes. These
+    /// are the versions with the same major version and a lower Kubernetes
+    /// priority, like `v1beta2` and `v1alpha1` for `v1`. The versions are
+    /// returned in the order they appear in `known`.
+    pub fn superseded(&self, known: &[Version]) -> Vec<Version> {
+        known
+            .iter()
+            .filter(|version| {
+                version.major == self.major && version.priority_cmp(self) == Ordering::Less
+            })
+            .copied()
+            .collect()
+    }
+
+    /// Returns `true` if this version is among the `window` newest versions of
+    /// its track in `known`. A track is made up of all versions sharing the
+    /// same major version and stability (see [`Version::distance`]). Versions
+    /// of other tracks are ignored. Returns `false` if this version is not
+    /// part of `known`.
+    pub fn is_within_recent(&self, known: &[Version], window: usize) -> bool {
+        let mut track: Vec<_> = known
+            .iter()
+            .filter(|version| self.distance(version).is_some())
+            .collect();
+
+        track.sort_by(|lhs, rhs| rhs.priority_cmp(lhs));
+        track.dedup();
+
+        track.iter().take(window).any(|version| *version == self)
+    }
+
+    /// Returns `true` if this version is a legitimate successor of `prev`.
+    /// The allowed transitions are:
+    ///
+    /// - A higher major version, regardless of stability, like `v1` to
+    ///   `v2alpha1`.
+    /// - The same major version with a stability promotion, like `v1alpha3`
+    ///   to `v1beta1` or `v1beta2` to `v1`.
+    /// - The same major version and stability with a higher level, like
+    ///   `v1beta1` to `v1beta2`.
+    ///
+    /// Equal versions and any step backwards are rejected.
+    pub fn is_valid_successor_of(&self, prev: &Version) -> bool {
+        if self.major != prev.major {
+            return self.major > prev.major;
+        }
+
+        match self.stability_rank().cmp(&prev.stability_rank()) {
+            Ordering::Equal => prev.distance(self).is_some_and(|distance| distance > 0),
+            ordering => ordering == Ordering::Greater,
+        }
+    }
+}
+
+/// Creates a [`Version`] from a string literal, which is validated at compile
+/// time. The macro expands to a `const` item using [`Version::from_static`],
+/// so it can be used in `const` contexts and a typo fails the build instead of
+/// panicking at runtime:
+///
+/// ```
+/// use k8s_version::{version, Level, Version};
+///
+/// const V1BETA1: Version = version!("v1beta1");
+/// assert_eq!(V1BETA1, Version::new(1, Some(Level::Beta(1))));
+/// assert_eq!(version!("v2"), Version::new(2, None));
+/// ```
+///
+/// ```compile_fail
+/// let _ = k8s_version::version!("v1gamma1");
+/// ```
+///
+/// Only string literals are accepted. Use [`Version::from_str`] to parse other
+/// input.
+#[macro_export]
+macro_rules! version {
+    ($input:literal) => {{
+        const VERSION: $crate::Version = $crate::Version::from_static($input);
+        VERSION
+    }};
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for super::Version {}
+    impl Sealed for &str {}
+    impl Sealed for alloc::string::String {}
+}
+
+/// Conversion into a [`Version`], parsing the input if required. This allows
+/// functions to accept anything parseable into a version:
+///
+/// ```
+/// use k8s_version::{IntoVersion, Version, VersionParseError};
+///
+/// fn is_stable(version: impl IntoVersion) -> Result<bool, VersionParseError> {
+///     Ok(version.into_version()?.level.is_none())
+/// }
+///
+/// assert!(is_stable("v1").unwrap());
+/// ```
+///
+/// This trait is sealed and implemented for [`Version`], [`&str`] and
+/// [`String`].
+pub trait IntoVersion: private::Sealed {
+    fn into_version(self) -> Result<Version, VersionParseError>;
+}
+
+impl IntoVersion for Version {
+    fn into_version(self) -> Result<Version, VersionParseError> {
+        Ok(self)
+    }
+}
+
+impl IntoVersion for &str {
+    fn into_version(self) -> Result<Version, VersionParseError> {
+        Version::from_str(self)
+    }
+}
+
+impl IntoVersion for String {
+    fn into_version(self) -> Result<Version, VersionParseError> {
+        Version::from_str(&self)
+    }
+}
+
+/// Returns the major version shared by all `versions`, or [`None`] if the
+/// major versions differ or `versions` is empty.
+pub fn common_major(versions: &[Version]) -> Option<u64> {
+    let (first, rest) = versions.split_first()?;
+
+    rest.iter()
+        .all(|version| version.major == first.major)
+        .then_some(first.major)
+}
+
+/// Extension methods for iterators over [`Version`]s.
+pub trait VersionIteratorExt: Iterator<Item = Version> {
+    /// Returns the version with the highest Kubernetes priority (see
+    /// [`Version::priority_cmp`]), or [`None`] if the iterator is empty.
+    fn max_kube(self) -> Option<Version>;
+
+    /// Returns the stable version with the highest Kubernetes priority, or
+    /// [`None`] if the iterator contains no stable versions.
+    fn max_stable(self) -> Option<Version>;
+}
+
+impl<I> VersionIteratorExt for I
+where
+    I: Iterator<Item = Version>,
+{
+    fn max_kube(self) -> Option<Version> {
+        self.max_by(|lhs, rhs| lhs.priority_cmp(rhs))
+    }
+
+    fn max_stable(self) -> Option<Version> {
+        self.filter(|version| version.level.is_none()).max_kube()
+    }
+}
+
+/// Extension methods for slices of [`Version`]s.
+pub trait VersionSliceExt {
+    /// Sorts the versions by descending Kubernetes priority (see
+    /// [`Version::priority_cmp`]), so that GA versions come first. The sort is
+    /// stable, equal versions keep their order.
+    fn sort_by_priority(&mut self);
+}
+
+impl VersionSliceExt for [Version] {
+    fn sort_by_priority(&mut self) {
+        self.sort_by(|lhs, rhs| rhs.priority_cmp(lhs))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Version {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::new(u.arbitrary()?, u.arbitrary()?))
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl Version {
+    /// Returns a [`proptest`] strategy generating valid versions.
+    pub fn arb() -> impl proptest::strategy::Strategy<Value = Version> {
+        use proptest::prelude::*;
+
+        (any::<u64>(), proptest::option::of(Level::arb()))
+            .prop_map(|(major, level)| Version::new(major, level))
+    }
+
+    /// Returns a [`proptest`] strategy generating valid version strings, like
+    /// `v1` or `v2beta1`. The strings are generated from the grammar instead
+    /// of formatting [`Version::arb`], so that they can be used to test
+    /// parsing independently of [`Display`].
+    pub fn arb_string() -> impl proptest::strategy::Strategy<Value = String> {
+        "v(0|[1-9][0-9]{0,18})((alpha|beta)(0|[1-9][0-9]{0,18}))?"
+    }
+}
+
+/// The pattern describing the `v<MAJOR>(alpha|beta)<LEVEL>` grammar, without
+/// anchors. It is used in the JSON schemas of [`Version`] and
+/// [`ApiVersion`][crate::ApiVersion].
+#[cfg(feature = "schemars")]
+pub(crate) const VERSION_PATTERN: &str = r"v(0|[1-9][0-9]*)((alpha|beta)(0|[1-9][0-9]*))?";
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Version {
+    fn schema_name() -> String {
+        "Version".to_string()
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        use schemars::schema::{InstanceType, Metadata, SchemaObject, StringValidation};
+
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            metadata: Some(Box::new(Metadata {
+                description: Some(
+                    "A Kubernetes resource version, like v1, v2beta1 or v1alpha2".to_string(),
+                ),
+                ..Default::default()
+            })),
+            string: Some(Box::new(StringValidation {
+                max_length: Some(63),
+                pattern: Some(format!("^{VERSION_PATTERN}$")),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Version {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes a [`Version`] either from its string form (`"v1beta1"`) or
+/// from its structured form (`{"major": 1, "stability": "beta", "level": 1}`).
+/// The `level` field must be omitted (or `null`) for `stable` versions.
+///
+/// The structured form is only accepted by self-describing, human-readable
+/// formats, like JSON. Other formats, like bincode, only accept the string
+/// form, which is also what [`Version`]s are serialized as.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Version {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::{value::MapAccessDeserializer, Error, MapAccess, Visitor};
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "lowercase")]
+        enum StabilityTag {
+            Stable,
+            Beta,
+            Alpha,
+        }
+
+        #[derive(serde::Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct Fields {
+            major: u64,
+            stability: StabilityTag,
+            level: Option<u64>,
+        }
+
+        struct VersionVisitor;
+
+        impl<'de> Visitor<'de> for VersionVisitor {
+            type Value = Version;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                f.write_str("a Kubernetes version string or struct")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Version::from_str(value).map_err(E::custom)
+            }
+
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let fields: Fields =
+                    serde::Deserialize::deserialize(MapAccessDeserializer::new(map))?;
+
+                let level = match (fields.stability, fields.level) {
+                    (StabilityTag::Stable, None) => None,
+                    (StabilityTag::Beta, Some(level)) => Some(Level::Beta(level)),
+                    (StabilityTag::Alpha, Some(level)) => Some(Level::Alpha(level)),
+                    (StabilityTag::Stable, Some(_)) => {
+                        return Err(Error::custom("stable versions must not have a level"))
+                    }
+                    (_, None) => return Err(Error::missing_field("level")),
+                };
+
+                Ok(Version::new(fields.major, level))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(VersionVisitor)
+        } else {
+            deserializer.deserialize_str(VersionVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_support::{kubernetes_docs_versions, KUBERNETES_DOCS_PRIORITY};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("v1alpha12")]
+    #[case("v1alpha1")]
+    #[case("v1beta1")]
+    #[case("v1")]
+    fn valid_version(#[case] input: &str) {
+        let version = Version::from_str(input).unwrap();
+        assert_eq!(version.to_string(), input);
+    }
+
+    #[rstest]
+    #[case("v1, v1beta1, v1, v2alpha1", &["v1", "v1beta1", "v2alpha1"])]
+    #[case("v2alpha1,v1beta1,v2,v1beta1", &["v2", "v1beta1", "v2alpha1"])]
+    #[case("v1", &["v1"])]
+    #[case(" ", &[])]
+    fn parse_many_dedup_sorted(#[case] input: &str, #[case] expected: &[&str]) {
+        let versions: Vec<_> = Version::parse_many_dedup_sorted(input)
+            .unwrap()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        assert_eq!(versions, expected);
+    }
+
+    #[rstest]
+    #[case("v1,v1beta1,v2alpha1", &["v1", "v1beta1", "v2alpha1"])]
+    #[case(" v2alpha1 , v1 ,v1", &["v2alpha1", "v1", "v1"])]
+    #[case("", &[])]
+    fn parse_list(#[case] input: &str, #[case] expected: &[&str]) {
+        let versions: Vec<_> = Version::parse_list(input)
+            .unwrap()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        assert_eq!(versions, expected);
+    }
+
+    #[rstest]
+    #[case("v1,", VersionListParseError::EmptyVersion { position: 1 })]
+    #[case("v1,,v2", VersionListParseError::EmptyVersion { position: 1 })]
+    #[case(",v1", VersionListParseError::EmptyVersion { position: 0 })]
+    #[case("v1, v1beta1, v1gamma1", VersionListParseError::ParseVersion {
+        source: VersionParseError::InvalidFormat,
+        input: "v1gamma1".to_string(),
+        position: 2,
+    })]
+    fn parse_list_invalid(#[case] input: &str, #[case] expected: VersionListParseError) {
+        assert_eq!(Version::parse_list(input).unwrap_err(), expected);
+    }
+
+    #[rstest]
+    #[case("v1, v1gamma1")]
+    #[case("v1,")]
+    fn parse_many_dedup_sorted_invalid(#[case] input: &str) {
+        assert!(Version::parse_many_dedup_sorted(input).is_err());
+    }
+
+    #[rstest]
+    #[case("\"v1\"", Version::new(1, None))]
+    #[case("'v1beta1'", Version::new(1, Some(Level::Beta(1))))]
+    fn strip_quotes(#[case] input: &str, #[case] expected: Version) {
+        let version = Version::from_str_with_options(input, &ParseOptions::lenient()).unwrap();
+        assert_eq!(version, expected);
+
+        let err = Version::from_str(input).unwrap_err();
+        assert_eq!(err, VersionParseError::InvalidFormat);
+    }
+
+    #[rstest]
+    #[case("V1", "v1")]
+    #[case("V1Beta1", "v1beta1")]
+    #[case("v2ALPHA1", "v2alpha1")]
+    #[case("v1", "v1")]
+    fn from_str_normalized(#[case] input: &str, #[case] expected: &str) {
+        let version = Version::from_str_normalized(input).unwrap();
+        assert_eq!(version.to_string(), expected);
+
+        if input != expected {
+            assert!(Version::from_str(input).is_err());
+        }
+    }
+
+    #[rstest]
+    #[case("\u{feff}v1")]
+    #[case("v1\r\n")]
+    #[case("v1\n")]
+    #[case("\u{feff}\"v1\"\r\n")]
+    fn strip_bom_and_line_endings(#[case] input: &str) {
+        let version = Version::from_str_with_options(input, &ParseOptions::lenient()).unwrap();
+        assert_eq!(version, Version::new(1, None));
+
+        assert!(Version::from_str(input).is_err());
+    }
+
+    #[rstest]
+    #[case("v1beta1", Version::new(1, Some(Level::Beta(1))))]
+    #[case("v0", Version::new(0, None))]
+    #[case("v1beta0", Version::new(1, Some(Level::Beta(0))))]
+    fn eq(#[case] input: &str, #[case] expected: Version) {
+        assert_eq!(Version::from_str(input).unwrap(), expected);
+    }
+
+    #[test]
+    fn hash() {
+        use std::collections::HashSet;
+
+        let served: HashSet<_> = ["v1", "v1beta1", "v1", "v2alpha1"]
+            .iter()
+            .map(|input| Version::from_str(input).unwrap())
+            .collect();
+
+        assert_eq!(served.len(), 3);
+        assert!(served.contains(&Version::new(1, Some(Level::Beta(1)))));
+        assert!(!served.contains(&Version::new(1, Some(Level::Alpha(1)))));
+    }
+
+    #[rstest]
+    #[case(format!("v1beta{}", "1".repeat(58)))]
+    #[case(format!("v1beta1{}", "x".repeat(57)))]
+    #[case(format!("v{}", "1".repeat(63)))]
+    #[case(format!("v{}", "1".repeat(100)))]
+    fn too_long(#[case] input: String) {
+        assert!(input.len() > 63);
+        assert_eq!(
+            Version::from_str(&input).unwrap_err(),
+            VersionParseError::InvalidFormat
+        );
+    }
+
+    #[rstest]
+    #[case("v1betä1")]
+    #[case("v1bätä1")]
+    #[case("ä")]
+    #[case("v1\u{200b}")]
+    fn non_ascii(#[case] input: &str) {
+        let err = Version::from_str(input).unwrap_err();
+        assert_eq!(err, VersionParseError::NonAscii);
+    }
+
+    #[rstest]
+    #[case("v1beta1x", 'x', 7)]
+    #[case("v1alpha2-", '-', 8)]
+    #[case("v1-2", '-', 2)]
+    #[case("v1-1", '-', 2)]
+    #[case("v1beta1v1", 'v', 7)]
+    #[case("v1 ", ' ', 2)]
+    #[case("v10_", '_', 3)]
+    fn trailing_characters(#[case] input: &str, #[case] character: char, #[case] index: usize) {
+        let expected = VersionParseError::TrailingCharacters { character, index };
+        assert_eq!(Version::from_str(input).unwrap_err(), expected);
+        assert_eq!(Version::from_ascii_unchecked(input).unwrap_err(), expected);
+    }
+
+    #[test]
+    fn trailing_characters_message() {
+        let err = Version::from_str("v1beta1x").unwrap_err();
+        assert_eq!(err.to_string(), "unexpected character 'x' at index 7");
+    }
+
+    #[test]
+    fn empty() {
+        let err = Version::from_str("").unwrap_err();
+        assert_eq!(err, VersionParseError::InvalidFormat);
+        assert_eq!(err.render_with_source(""), format!("\n^ {err}"));
+    }
+
+    #[rstest]
+    #[case("v0", Ok(Version::ga(0)))]
+    #[case("v1", Ok(Version::ga(1)))]
+    #[case("v18446744073709551615", Ok(Version::ga(u64::MAX)))]
+    #[case("v1beta1", Ok(Version::beta(1, 1)))]
+    #[case("v12alpha10", Ok(Version::alpha(12, 10)))]
+    #[case("v1.25", Err(VersionParseError::LooksLikeClusterVersion))]
+    #[case("v1.25.3", Err(VersionParseError::LooksLikeClusterVersion))]
+    #[case("v1.x", Err(VersionParseError::TrailingCharacters { character: '.', index: 2 }))]
+    #[case("v1Beta1", Err(VersionParseError::TrailingCharacters { character: 'B', index: 2 }))]
+    #[case("V1", Err(VersionParseError::InvalidFormat))]
+    #[case("v", Err(VersionParseError::InvalidFormat))]
+    #[case("vbeta1", Err(VersionParseError::InvalidFormat))]
+    #[case("v1beta", Err(VersionParseError::InvalidFormat))]
+    #[case("v1beta-1", Err(VersionParseError::InvalidFormat))]
+    #[case("v1gamma1", Err(VersionParseError::InvalidFormat))]
+    #[case(" v1", Err(VersionParseError::InvalidFormat))]
+    fn from_str(#[case] input: &str, #[case] expected: Result<Version, VersionParseError>) {
+        assert_eq!(Version::from_str(input), expected);
+    }
+
+    #[rstest]
+    #[case("v1v1")]
+    #[case("v1gamma1")]
+    #[case("v1beta")]
+    #[case(&format!("v1beta{}", "1".repeat(58)))]
+    fn invalid_format(#[case] input: &str) {
+        let err = Version::from_str(input).unwrap_err();
+        assert_eq!(err, VersionParseError::InvalidFormat);
+    }
+
+    #[rstest]
+    #[case("vbeta1")]
+    #[case("valpha1")]
+    #[case("v")]
+    fn missing_major(#[case] input: &str) {
+        let err = Version::from_str(input).unwrap_err();
+        assert_eq!(err, VersionParseError::InvalidFormat);
+    }
+
+    #[rstest]
+    #[case("v01")]
+    #[case("v00")]
+    #[case("v010beta1")]
+    fn leading_zero_major(#[case] input: &str) {
+        let err = Version::from_str(input).unwrap_err();
+        assert_eq!(err, VersionParseError::InvalidFormat);
+    }
+
+    #[rstest]
+    #[case("v1beta01")]
+    #[case("v1alpha00")]
+    fn leading_zero_level(#[case] input: &str) {
+        let err = Version::from_str(input).unwrap_err();
+        assert_eq!(
+            err,
+            VersionParseError::ParseLevel {
+                source: ParseLevelError::InvalidFormat
+            }
+        );
+    }
+
+    #[rstest]
+    #[case("v1alpha12")]
+    #[case("v1alpha1")]
+    #[case("v1beta1")]
+    #[case("v10")]
+    #[case("v1")]
+    fn from_ascii_unchecked(#[case] input: &str) {
+        let checked = Version::from_str(input).unwrap();
+        let unchecked = Version::from_ascii_unchecked(input).unwrap();
+        assert_eq!(checked, unchecked);
+    }
+
+    #[rstest]
+    #[case("v1alpha12")]
+    #[case("v1beta1")]
+    #[case("v10")]
+    #[case("v1")]
+    fn from_static(#[case] input: &str) {
+        assert!(Version::is_valid(input));
+        assert_eq!(
+            Version::from_static(input),
+            Version::from_str(input).unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("v")]
+    #[case("1beta1")]
+    #[case("vbeta1")]
+    #[case("v1beta")]
+    #[case("v1gamma1")]
+    #[case("v1beta1x")]
+    #[case("v99999999999999999999")]
+    #[case("v1bätä1")]
+    #[case("v01")]
+    #[case("v1beta01")]
+    fn is_valid_invalid(#[case] input: &str) {
+        assert!(!Version::is_valid(input));
+        assert!(Version::from_str(input).is_err());
+    }
+
+    #[test]
+    fn from_static_const() {
+        const V2ALPHA1: Version = Version::from_static("v2alpha1");
+        const _: () = assert!(Version::is_valid("v1beta1"));
+
+        assert_eq!(V2ALPHA1, Version::new(2, Some(Level::Alpha(1))));
+    }
+
+    #[rstest]
+    #[case("1beta1")]
+    #[case("vbeta1")]
+    #[case("v")]
+    #[case("v01")]
+    fn from_ascii_unchecked_invalid(#[case] input: &str) {
+        let err = Version::from_ascii_unchecked(input).unwrap_err();
+        assert_eq!(err, VersionParseError::InvalidFormat);
+    }
+
+    #[rstest]
+    #[case(
+        "v1beta1 (deprecated)",
+        Version::new(1, Some(Level::Beta(1))),
+        Some("deprecated")
+    )]
+    #[case("v2  ( served only ) ", Version::new(2, None), Some("served only"))]
+    #[case("v1", Version::new(1, None), None)]
+    fn from_str_with_annotation(
+        #[case] input: &str,
+        #[case] version: Version,
+        #[case] annotation: Option<&str>,
+    ) {
+        let (parsed, parsed_annotation) = Version::from_str_with_annotation(input).unwrap();
+        assert_eq!(parsed, version);
+        assert_eq!(parsed_annotation.as_deref(), annotation);
+    }
+
+    #[rstest]
+    #[case("v1beta1 deprecated")]
+    #[case("v1beta1 (deprecated")]
+    fn from_str_with_invalid_annotation(#[case] input: &str) {
+        let err = Version::from_str_with_annotation(input).unwrap_err();
+        assert_eq!(err, VersionParseError::InvalidAnnotation);
+        assert!(Version::from_str(input).is_err());
+    }
+
+    #[rstest]
+    #[case(Version::new(0, None))]
+    #[case(Version::new(1, None))]
+    #[case(Version::new(10, Some(Level::Beta(3))))]
+    #[case(Version::new(2, Some(Level::Alpha(100))))]
+    #[case(Version::new(u64::MAX, Some(Level::Alpha(u64::MAX))))]
+    fn to_owned_string(#[case] input: Version) {
+        let owned = input.to_owned_string();
+        assert_eq!(owned, input.to_string());
+        assert_eq!(owned.capacity(), owned.len());
+    }
+
+    #[test]
+    fn copy_semantics() {
+        fn major(version: Version) -> u64 {
+            version.major
+        }
+
+        let version = Version::new(1, Some(Level::Beta(2)));
+        assert_eq!(major(version), 1);
+        assert_eq!(version.level, Some(Level::Beta(2)));
+    }
+
+    #[test]
+    fn format_into() {
+        let mut buf = String::new();
+
+        for input in ["v1", "v2beta1", "v3alpha12"] {
+            Version::from_str(input).unwrap().format_into(&mut buf);
+            buf.push(',');
+        }
+
+        assert_eq!(buf, "v1,v2beta1,v3alpha12,");
+    }
+
+    #[rstest]
+    #[case("v1beta2", "1beta2")]
+    #[case("v3alpha1", "3alpha1")]
+    #[case("v1", "1")]
+    fn display_compact(#[case] input: &str, #[case] expected: &str) {
+        let version = Version::from_str(input).unwrap();
+        assert_eq!(version.display_compact(), expected);
+        assert!(Version::from_str(expected).is_err());
+    }
+
+    #[rstest]
+    #[case("v1beta99999999999999999999")]
+    #[case("v1alpha18446744073709551616")]
+    fn level_overflow(#[case] input: &str) {
+        let err = Version::from_str(input).unwrap_err();
+        assert!(matches!(
+            err,
+            VersionParseError::ParseLevel {
+                source: ParseLevelError::ParseVersion { .. }
+            }
+        ));
+    }
+
+    #[rstest]
+    #[case("v1.28.3")]
+    #[case("v1.28")]
+    #[case("v1.28.3-gke.1")]
+    fn looks_like_cluster_version(#[case] input: &str) {
+        let err = Version::from_str(input).unwrap_err();
+        assert_eq!(err, VersionParseError::LooksLikeClusterVersion);
+    }
+
+    #[test]
+    fn major_overflow() {
+        let err = Version::from_str("v99999999999999999999").unwrap_err();
+        assert!(matches!(err, VersionParseError::ParseMajorVersion { .. }));
+    }
+
+    #[rstest]
+    #[case("v1", true)]
+    #[case("v10", true)]
+    #[case("v1beta1", false)]
+    #[case("v2alpha3", false)]
+    fn is_ga(#[case] input: &str, #[case] expected: bool) {
+        let version = Version::from_str(input).unwrap();
+        assert_eq!(version.is_ga(), expected);
+        assert_eq!(version.is_ga(), version.is_stable());
+    }
+
+    #[rstest]
+    #[case("v1", false, false)]
+    #[case("v1beta1", true, false)]
+    #[case("v2alpha3", false, true)]
+    fn is_prerelease(#[case] input: &str, #[case] beta: bool, #[case] alpha: bool) {
+        let version = Version::from_str(input).unwrap();
+        assert_eq!(version.is_beta(), beta);
+        assert_eq!(version.is_alpha(), alpha);
+        assert_eq!(version.is_prerelease(), beta || alpha);
+        assert_eq!(version.is_prerelease(), !version.is_stable());
+    }
+
+    #[test]
+    fn try_from() {
+        let expected = Version::new(1, Some(Level::Beta(1)));
+
+        assert_eq!(Version::try_from(String::from("v1beta1")), Ok(expected));
+        assert_eq!(Version::try_from(b"v1beta1".as_slice()), Ok(expected));
+        assert_eq!(
+            Version::try_from(b"v1\xff".as_slice()),
+            Err(VersionParseError::NonAscii)
+        );
+        assert_eq!(
+            Version::try_from("v1betä1".as_bytes()),
+            Err(VersionParseError::NonAscii)
+        );
+    }
+
+    #[test]
+    fn version_macro() {
+        const V2ALPHA1: Version = crate::version!("v2alpha1");
+
+        assert_eq!(V2ALPHA1, Version::new(2, Some(Level::Alpha(1))));
+        assert_eq!(crate::version!("v1"), Version::new(1, None));
+    }
+
+    #[rstest]
+    #[case("v1", "v1beta1")]
+    #[case("v2", "v1")]
+    #[case("v1beta1", "v2alpha1")]
+    #[case("v11beta2", "v10beta3")]
+    #[case("v1alpha2", "v1alpha1")]
+    fn is_newer_than(#[case] newer: Version, #[case] older: Version) {
+        assert!(newer.is_newer_than(&older));
+        assert!(!older.is_newer_than(&newer));
+        assert!(older.is_older_than(&newer));
+        assert!(!newer.is_older_than(&older));
+    }
+
+    #[test]
+    fn is_newer_than_same() {
+        let version = Version::beta(1, 1);
+
+        assert!(!version.is_newer_than(&version));
+        assert!(!version.is_older_than(&version));
+    }
+
+    #[rstest]
+    #[case("v1")]
+    #[case("v0")]
+    #[case("v2beta3")]
+    #[case("v1alpha0")]
+    #[case("v1073741823")]
+    #[case("v1073741823beta4294967295")]
+    fn packed_round_trip(#[case] version: Version) {
+        let packed = version.to_packed().unwrap();
+        assert_eq!(Version::from_packed(packed), Some(version));
+    }
+
+    #[test]
+    fn packed_order() {
+        let mut versions: Vec<_> = ["v11alpha2", "v1", "v10beta3", "v2", "v12alpha1"]
+            .iter()
+            .map(|input| Version::from_str(input).unwrap())
+            .collect();
+        versions.sort_by_key(|version| core::cmp::Reverse(version.to_packed().unwrap()));
+
+        let versions: Vec<_> = versions.iter().map(ToString::to_string).collect();
+        assert_eq!(versions, ["v2", "v1", "v10beta3", "v12alpha1", "v11alpha2"]);
+    }
+
+    #[rstest]
+    #[case(Version::ga(Version::MAX_PACKED_MAJOR + 1))]
+    #[case(Version::beta(1, Version::MAX_PACKED_LEVEL + 1))]
+    #[case(Version::alpha(u64::MAX, 1))]
+    fn packed_out_of_range(#[case] version: Version) {
+        assert_eq!(version.to_packed(), None);
+    }
+
+    #[rstest]
+    #[case(3 << 62)]
+    #[case(2 << 62 | 1 << 32 | 1)]
+    fn from_packed_invalid(#[case] packed: u64) {
+        assert_eq!(Version::from_packed(packed), None);
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(Version::default().to_string(), "v1");
+    }
+
+    #[test]
+    fn into_string() {
+        let version = Version::new(2, Some(Level::Beta(1)));
+
+        assert_eq!(String::from(version), "v2beta1");
+        assert!(matches!(version.to_tuple(), (2, Some(Level::Beta(1)))));
+    }
+
+    #[rstest]
+    #[case(Version::ga(2), "v2")]
+    #[case(Version::beta(1, 3), "v1beta3")]
+    #[case(Version::alpha(3, 1), "v3alpha1")]
+    fn constructors(#[case] input: Version, #[case] expected: &str) {
+        assert_eq!(input, Version::from_str(expected).unwrap());
+    }
+
+    #[test]
+    fn new_const() {
+        const V1: Version = Version::new(1, None);
+        const V2BETA1: Version = Version::new(2, Some(Level::Beta(1)));
+        const _: () = assert!(V2BETA1.major() == 2 && V1.level().is_none());
+
+        assert_eq!(V1, Version::from_str("v1").unwrap());
+        assert_eq!(V2BETA1.level(), Some(Level::Beta(1)));
+    }
+
+    #[test]
+    fn is_prerelease_const() {
+        const V1BETA1: Version = Version::from_static("v1beta1");
+        const _: () = assert!(V1BETA1.is_beta() && V1BETA1.is_prerelease());
+    }
+
+    #[rstest]
+    #[case("v1", Stability::Stable)]
+    #[case("v2beta3", Stability::Beta)]
+    #[case("v1alpha1", Stability::Alpha)]
+    fn stability(#[case] input: &str, #[case] expected: Stability) {
+        assert_eq!(Version::from_str(input).unwrap().stability(), expected);
+    }
+
+    #[test]
+    fn stability_rank() {
+        let ga = Version::new(1, None);
+        let beta = Version::new(1, Some(Level::Beta(1)));
+        let alpha = Version::new(1, Some(Level::Alpha(1)));
+
+        assert_eq!(ga.stability_rank(), 2);
+        assert_eq!(beta.stability_rank(), 1);
+        assert_eq!(alpha.stability_rank(), 0);
+
+        for (lhs, rhs) in [(&ga, &beta), (&beta, &alpha), (&ga, &alpha)] {
+            assert!(lhs.stability_rank() > rhs.stability_rank());
+            assert_eq!(lhs.partial_cmp(rhs), Some(Ordering::Greater));
+        }
+    }
+
+    #[rstest]
+    #[case(Version::new(1, None), Version::new(2, None))]
+    #[case(Version::new(1, Some(Level::Beta(2))), Version::new(2, None))]
+    #[case(Version::new(u64::MAX - 1, None), Version::new(u64::MAX, None))]
+    #[case(
+        Version::new(u64::MAX, Some(Level::Alpha(1))),
+        Version::new(u64::MAX, None)
+    )]
+    fn saturating_bump_major(#[case] input: Version, #[case] expected: Version) {
+        assert_eq!(input.saturating_bump_major(), expected);
+    }
+
+    #[rstest]
+    #[case("v1alpha5", "v1")]
+    #[case("v2beta3", "v2")]
+    #[case("v3", "v3")]
+    fn to_ga(#[case] input: &str, #[case] expected: &str) {
+        let mut version = Version::from_str(input).unwrap();
+        let expected = Version::from_str(expected).unwrap();
+        assert_eq!(version.to_ga(), expected);
+
+        version.promote_to_ga();
+        assert_eq!(version, expected);
+    }
+
+    #[test]
+    fn ga_range() {
+        let versions: Vec<_> = Version::ga_range(1..=3).map(|v| v.to_string()).collect();
+        assert_eq!(versions, ["v1", "v2", "v3"]);
+
+        assert_eq!(Version::ga_range(2..2).count(), 0);
+    }
+
+    #[rstest]
+    #[case("v1alpha2", "v1beta1")]
+    #[case("v1beta1", "v1")]
+    #[case("v2beta3", "v2")]
+    #[case("v1", "v1")]
+    fn promote(#[case] input: &str, #[case] expected: &str) {
+        let version = Version::from_str(input).unwrap();
+        assert_eq!(version.promote().to_string(), expected);
+    }
+
+    #[rstest]
+    #[case("v1", "v1beta1")]
+    #[case("v1beta2", "v1alpha1")]
+    #[case("v2alpha3", "v2alpha3")]
+    fn demote(#[case] input: &str, #[case] expected: &str) {
+        let version = Version::from_str(input).unwrap();
+        assert_eq!(version.demote().to_string(), expected);
+    }
+
+    #[rstest]
+    #[case("v2", VersionClass::Ga { major: 2 })]
+    #[case("v1beta3", VersionClass::Beta { major: 1, n: 3 })]
+    #[case("v3alpha1", VersionClass::Alpha { major: 3, n: 1 })]
+    fn classify(#[case] input: &str, #[case] expected: VersionClass) {
+        let version = Version::from_str(input).unwrap();
+        assert_eq!(version.classify(), expected);
+    }
+
+    #[test]
+    fn classify_as_key() {
+        let classes: std::collections::HashSet<_> = ["v1", "v1", "v1beta1"]
+            .iter()
+            .map(|input| Version::from_str(input).unwrap().classify())
+            .collect();
+
+        assert_eq!(classes.len(), 2);
+        assert!(classes.contains(&VersionClass::Beta { major: 1, n: 1 }));
+    }
+
+    #[test]
+    fn priority_cmp() {
+        let mut versions = kubernetes_docs_versions();
+        versions.sort_by(|lhs, rhs| rhs.priority_cmp(lhs));
+
+        let versions: Vec<_> = versions.iter().map(ToString::to_string).collect();
+        assert_eq!(versions, KUBERNETES_DOCS_PRIORITY);
+    }
+
+    #[rstest]
+    #[case("v1gamma1", 2)]
+    #[case("1beta1", 0)]
+    #[case("vbeta1", 1)]
+    #[case("v1beta", 6)]
+    #[case("v1beta1x", 7)]
+    #[case("v99999999999999999999", 1)]
+    #[case("v1beta99999999999999999999", 6)]
+    #[case("v1bätä1", 3)]
+    #[case("v01", 1)]
+    #[case("v1beta01", 6)]
+    fn render_with_source(#[case] input: &str, #[case] caret: usize) {
+        let err = Version::from_str(input).unwrap_err();
+        let rendered = err.render_with_source(input);
+        let (source, pointer) = rendered.split_once('\n').unwrap();
+
+        assert_eq!(source, input);
+        assert_eq!(pointer, format!("{}^ {err}", " ".repeat(caret)));
+    }
+
+    #[rstest]
+    #[case("v1beta1x", "v1", 2)]
+    #[case("v1beta1x", "", 0)]
+    #[case("v1beta1x", "v1betaä", 6)]
+    fn render_with_other_source(#[case] parsed: &str, #[case] input: &str, #[case] caret: usize) {
+        let err = Version::from_str(parsed).unwrap_err();
+        let rendered = err.render_with_source(input);
+        let (_, pointer) = rendered.split_once('\n').unwrap();
+
+        assert_eq!(pointer, format!("{}^ {err}", " ".repeat(caret)));
+    }
+
+    #[test]
+    fn into_version() {
+        fn major(version: impl IntoVersion) -> u64 {
+            version.into_version().unwrap().major
+        }
+
+        assert_eq!(major(Version::new(1, None)), 1);
+        assert_eq!(major("v2beta1"), 2);
+        assert_eq!(major(String::from("v3alpha1")), 3);
+        assert!("v1gamma1".into_version().is_err());
+    }
+
+    #[rstest]
+    #[case(&["v1", "v1beta1", "v1alpha2"], Some(1))]
+    #[case(&["v2beta1"], Some(2))]
+    #[case(&["v1", "v2beta1"], None)]
+    #[case(&[], None)]
+    fn common_major(#[case] input: &[&str], #[case] expected: Option<u64>) {
+        let versions: Vec<_> = input
+            .iter()
+            .map(|input| Version::from_str(input).unwrap())
+            .collect();
+
+        assert_eq!(super::common_major(&versions), expected);
+    }
+
+    #[rstest]
+    #[case(&["v1beta1", "v2alpha1", "v1", "v1beta2"], Some("v1"), Some("v1"))]
+    #[case(&["v1beta1", "v2alpha1", "v1beta2"], Some("v1beta2"), None)]
+    #[case(&["v1", "v3", "v2", "v4beta1"], Some("v3"), Some("v3"))]
+    #[case(&[], None, None)]
+    fn version_iterator_ext(
+        #[case] input: &[&str],
+        #[case] kube: Option<&str>,
+        #[case] stable: Option<&str>,
+    ) {
+        let versions = || input.iter().map(|input| Version::from_str(input).unwrap());
+
+        assert_eq!(
+            versions().max_kube().map(|v| v.to_string()).as_deref(),
+            kube
+        );
+        assert_eq!(
+            versions().max_stable().map(|v| v.to_string()).as_deref(),
+            stable
+        );
+    }
+
+    #[test]
+    fn sort_by_priority() {
+        let mut versions = kubernetes_docs_versions();
+        versions.sort_by_priority();
+
+        let versions: Vec<_> = versions.iter().map(ToString::to_string).collect();
+        assert_eq!(versions, KUBERNETES_DOCS_PRIORITY);
+    }
+
+    #[rstest]
+    #[case(&["v1beta2", "v2alpha1", "v1"], Some("v1"))]
+    #[case(&["v2alpha1", "v1beta2"], Some("v1beta2"))]
+    #[case(&["v2alpha1"], Some("v2alpha1"))]
+    #[case(&[], None)]
+    fn preferred(#[case] input: &[&str], #[case] expected: Option<&str>) {
+        let versions = input.iter().map(|input| Version::from_str(input).unwrap());
+        let expected = expected.map(|expected| Version::from_str(expected).unwrap());
+        assert_eq!(Version::preferred(versions), expected);
+    }
+
+    #[rstest]
+    #[case("v1", &["v1beta3", "v1beta2", "v1beta1", "v1alpha2", "v1alpha1"])]
+    #[case("v1beta2", &["v1beta1", "v1alpha2", "v1alpha1"])]
+    #[case("v1alpha1", &[])]
+    #[case("v2", &["v2beta1"])]
+    fn superseded(#[case] input: &str, #[case] expected: &[&str]) {
+        let known: Vec<_> = [
+            "v2", "v2beta1", "v1", "v1beta3", "v1beta2", "v1beta1", "v1alpha2", "v1alpha1",
+        ]
+        .iter()
+        .map(|input| Version::from_str(input).unwrap())
+        .collect();
+
+        let version = Version::from_str(input).unwrap();
+        let superseded: Vec<_> = version
+            .superseded(&known)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        assert_eq!(superseded, expected);
+    }
+
+    #[test]
+    fn ord() {
+        let mut versions = kubernetes_docs_versions();
+        versions.sort();
+        versions.reverse();
+
+        let versions: Vec<_> = versions.iter().map(ToString::to_string).collect();
+        assert_eq!(versions, KUBERNETES_DOCS_PRIORITY);
+
+        let v1 = Version::new(1, None);
+        assert!(v1 > Version::new(1, Some(Level::Beta(1))));
+        assert!(v1 > Version::new(2, Some(Level::Alpha(1))));
+        assert!(Version::new(2, None) > v1);
+    }
+
+    #[rstest]
+    #[case("v2", (2, 2, 0))]
+    #[case("v10beta3", (1, 10, 3))]
+    #[case("v12alpha1", (0, 12, 1))]
+    fn priority_key(#[case] input: &str, #[case] expected: (u8, u64, u64)) {
+        assert_eq!(Version::from_str(input).unwrap().priority_key(), expected);
+    }
+
+    #[test]
+    fn sort_by_priority_key() {
+        let mut versions: Vec<_> = ["v1beta1", "v2", "v1", "v3alpha1"]
+            .iter()
+            .map(|input| (Version::from_str(input).unwrap(), *input))
+            .collect();
+
+        versions.sort_by_key(|(version, _)| std::cmp::Reverse(version.priority_key()));
+        let versions: Vec<_> = versions.iter().map(|(_, input)| *input).collect();
+        assert_eq!(versions, ["v2", "v1", "v1beta1", "v3alpha1"]);
+    }
+
+    /// A reference implementation of the Kubernetes version priority, in the
+    /// shape of `CompareKubeAwareVersionStrings` from apimachinery: GA beats
+    /// beta beats alpha, and versions of the same stability are compared by
+    /// their major and then their level number.
+    fn reference_cmp(lhs: &Version, rhs: &Version) -> Ordering {
+        use VersionClass::{Alpha, Beta, Ga};
+
+        match (lhs.classify(), rhs.classify()) {
+            (Ga { major: lhs }, Ga { major: rhs }) => lhs.cmp(&rhs),
+            (Beta { major: lm, n: ln }, Beta { major: rm, n: rn })
+            | (Alpha { major: lm, n: ln }, Alpha { major: rm, n: rn }) => {
+                if lm != rm {
+                    lm.cmp(&rm)
+                } else {
+                    ln.cmp(&rn)
+                }
+            }
+            (Ga { .. }, _) | (Beta { .. }, Alpha { .. }) => Ordering::Greater,
+            (_, Ga { .. }) | (Alpha { .. }, Beta { .. }) => Ordering::Less,
+        }
+    }
+
+    #[test]
+    fn priority_cmp_matches_reference() {
+        let versions = crate::test_support::random_versions(20_000);
+
+        for pair in versions.chunks_exact(2) {
+            let (lhs, rhs) = (&pair[0], &pair[1]);
+            assert_eq!(
+                lhs.priority_cmp(rhs),
+                reference_cmp(lhs, rhs),
+                "{lhs} vs {rhs}"
+            );
+        }
+    }
+
+    #[rstest]
+    #[case("v1beta4", true)]
+    #[case("v1beta3", true)]
+    #[case("v1beta2", false)]
+    #[case("v1alpha2", true)]
+    #[case("v1alpha1", true)]
+    #[case("v1", true)]
+    #[case("v2beta1", true)]
+    #[case("v1beta5", false)]
+    fn is_within_recent(#[case] input: &str, #[case] expected: bool) {
+        let known: Vec<_> = [
+            "v1beta2", "v1beta4", "v1", "v1beta3", "v1beta1", "v1alpha1", "v1alpha2", "v2beta1",
+            "v1beta4",
+        ]
+        .iter()
+        .map(|input| Version::from_str(input).unwrap())
+        .collect();
+
+        let version = Version::from_str(input).unwrap();
+        assert_eq!(version.is_within_recent(&known, 2), expected);
+    }
+
+    #[rstest]
+    #[case("v2alpha1", "v1", true)]
+    #[case("v2", "v1", true)]
+    #[case("v1beta1", "v1alpha3", true)]
+    #[case("v1", "v1beta2", true)]
+    #[case("v1", "v1alpha1", true)]
+    #[case("v1beta2", "v1beta1", true)]
+    #[case("v1beta1", "v1beta2", false)]
+    #[case("v1alpha1", "v1beta1", false)]
+    #[case("v1beta1", "v1", false)]
+    #[case("v1", "v2alpha1", false)]
+    #[case("v1", "v1", false)]
+    #[case("v1beta1", "v1beta1", false)]
+    fn is_valid_successor_of(#[case] input: &str, #[case] prev: &str, #[case] expected: bool) {
+        let input = Version::from_str(input).unwrap();
+        let prev = Version::from_str(prev).unwrap();
+        assert_eq!(input.is_valid_successor_of(&prev), expected);
+    }
+
+    #[rstest]
+    #[case("v1", Stability::Beta, Ordering::Greater)]
+    #[case("v1", Stability::Stable, Ordering::Equal)]
+    #[case("v2beta3", Stability::Beta, Ordering::Equal)]
+    #[case("v1alpha1", Stability::Beta, Ordering::Less)]
+    #[case("v9alpha9", Stability::Alpha, Ordering::Equal)]
+    fn partial_ord_stability(
+        #[case] input: &str,
+        #[case] stability: Stability,
+        #[case] expected: Ordering,
+    ) {
+        let version = Version::from_str(input).unwrap();
+        assert_eq!(version.partial_cmp(&stability), Some(expected));
+    }
+
+    #[rstest]
+    #[case("v1beta1", "v1beta4", Some(3))]
+    #[case("v1beta4", "v1beta1", Some(-3))]
+    #[case("v1alpha2", "v1alpha2", Some(0))]
+    #[case("v1", "v1", Some(0))]
+    #[case("v1", "v2", None)]
+    #[case("v1beta1", "v2beta1", None)]
+    #[case("v1alpha1", "v1beta1", None)]
+    #[case("v1beta1", "v1", None)]
+    fn distance(#[case] input: &str, #[case] other: &str, #[case] expected: Option<i64>) {
+        let input = Version::from_str(input).unwrap();
+        let other = Version::from_str(other).unwrap();
+        assert_eq!(input.distance(&other), expected);
+    }
+
+    #[cfg(feature = "schemars")]
+    #[rstest]
+    #[case("v1", true)]
+    #[case("v10beta3", true)]
+    #[case("v2alpha1", true)]
+    #[case("v01", false)]
+    #[case("v1gamma1", false)]
+    #[case("v1beta1x", false)]
+    fn json_schema_pattern(#[case] input: &str, #[case] valid: bool) {
+        let schema = schemars::schema_for!(Version).schema;
+        let pattern = schema.string.unwrap().pattern.unwrap();
+
+        assert_eq!(regex::Regex::new(&pattern).unwrap().is_match(input), valid);
+        assert_eq!(Version::from_str(input).is_ok(), valid);
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_version() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let bytes: Vec<u8> = (0..=255).collect();
+        let mut u = Unstructured::new(&bytes);
+
+        while !u.is_empty() {
+            let version = Version::arbitrary(&mut u).unwrap();
+            assert_eq!(Version::from_str(&version.to_string()), Ok(version));
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    fn packable() -> impl proptest::strategy::Strategy<Value = Version> {
+        use proptest::prelude::*;
+
+        let major = 0..=Version::MAX_PACKED_MAJOR;
+        let level = 0..=Version::MAX_PACKED_LEVEL;
+
+        (major, level, 0..3u8).prop_map(|(major, level, rank)| match rank {
+            2 => Version::ga(major),
+            1 => Version::beta(major, level),
+            _ => Version::alpha(major, level),
+        })
+    }
+
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn proptest_display_round_trip(version in Version::arb()) {
+            proptest::prop_assert_eq!(Version::from_str(&version.to_string()), Ok(version));
+        }
+
+        #[test]
+        fn proptest_parse_round_trip(input in Version::arb_string()) {
+            let version = Version::from_str(&input).unwrap();
+            proptest::prop_assert_eq!(version.to_string(), input);
+        }
+
+        #[test]
+        fn proptest_ord_transitive(a in Version::arb(), b in Version::arb(), c in Version::arb()) {
+            if a <= b && b <= c {
+                proptest::prop_assert!(a <= c);
+            }
+        }
+
+        #[test]
+        fn proptest_packed_order(a in packable(), b in packable()) {
+            let (lhs, rhs) = (a.to_packed().unwrap(), b.to_packed().unwrap());
+            proptest::prop_assert_eq!(lhs.cmp(&rhs), a.priority_cmp(&b));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    #[case(r#""v1beta1""#, r#"{"major": 1, "stability": "beta", "level": 1}"#)]
+    #[case(r#""v2alpha3""#, r#"{"major": 2, "stability": "alpha", "level": 3}"#)]
+    #[case(r#""v1""#, r#"{"major": 1, "stability": "stable"}"#)]
+    fn deserialize_string_and_struct(#[case] string: &str, #[case] structured: &str) {
+        let from_string: Version = serde_json::from_str(string).unwrap();
+        let from_struct: Version = serde_json::from_str(structured).unwrap();
+
+        assert_eq!(from_string, from_struct);
+        assert_eq!(serde_json::to_string(&from_struct).unwrap(), string);
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    #[case(r#""v1gamma1""#)]
+    #[case(r#"{"major": 1, "stability": "stable", "level": 1}"#)]
+    #[case(r#"{"major": 1, "stability": "beta"}"#)]
+    fn deserialize_invalid(#[case] input: &str) {
+        assert!(serde_json::from_str::<Version>(input).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    #[case(Version::ga(1))]
+    #[case(Version::beta(2, 3))]
+    fn bincode_round_trip(#[case] version: Version) {
+        let bytes = bincode::serialize(&version).unwrap();
+        assert_eq!(bincode::deserialize::<Version>(&bytes).unwrap(), version);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_error_message() {
+        let err = serde_json::from_str::<Version>(r#""v1beta1x""#).unwrap_err();
+        let expected = VersionParseError::TrailingCharacters {
+            character: 'x',
+            index: 7,
+        };
+
+        assert!(err.to_string().starts_with(&expected.to_string()));
     }
 
     // #[rstest]
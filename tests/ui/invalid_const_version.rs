@@ -0,0 +1,7 @@
+use k8s_version::Version;
+
+const V1GAMMA1: Version = Version::from_static("v1gamma1");
+
+fn main() {
+    let _ = V1GAMMA1;
+}
@@ -0,0 +1,5 @@
+use k8s_version::version;
+
+fn main() {
+    let _ = version!("v1gamma1");
+}